@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use aoc::{
+    generate::{Generated, Rng},
+    parse, ParseError, Solution,
+};
+
+fn parse_number(input: &str, line: &str, number: Option<&str>) -> Result<u32, ParseError> {
+    let number = number.ok_or_else(|| ParseError::new(input, &line[line.len()..], "a number"))?;
+    parse::number(input, number)
+}
+
+fn parse_line(input: &str, line: &str) -> Result<(u32, u32), ParseError> {
+    let mut it = line.split_whitespace();
+    let left = parse_number(input, line, it.next())?;
+    let right = parse_number(input, line, it.next())?;
+    match it.next() {
+        Some(extra) => Err(ParseError::new(input, extra, "end of line")),
+        None => Ok((left, right)),
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Parsed<'a> = (Vec<u64>, Vec<u64>);
+    type Part1 = u64;
+    type Part2 = Result<u64, &'static str>;
+
+    fn parse(&self, input: &str) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+        let (mut list1, mut list2): (Vec<u64>, Vec<u64>) = parse::lines(input)
+            .map(|line| parse_line(input, line).map(|(l, r)| (u64::from(l), u64::from(r))))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        list1.sort_unstable();
+        list2.sort_unstable();
+        Ok((list1, list2))
+    }
+
+    /// IDs fit in 32 bits, so the distances cannot overflow before there
+    /// are billions of them.
+    fn part1(&self, (list1, list2): &(Vec<u64>, Vec<u64>)) -> u64 {
+        list1
+            .iter()
+            .zip(list2.iter())
+            .map(|(&l, &r)| l.abs_diff(r))
+            .sum::<u64>()
+    }
+
+    fn part2(&self, (list1, list2): &(Vec<u64>, Vec<u64>)) -> Result<u64, &'static str> {
+        let list2_occurrences = list2
+            .iter()
+            .fold(HashMap::<u64, u64>::new(), |mut agg, &elem| {
+                *agg.entry(elem).or_default() += 1;
+                agg
+            });
+
+        list1
+            .iter()
+            .try_fold(0u64, |sum, &elem| {
+                elem.checked_mul(list2_occurrences.get(&elem).copied().unwrap_or_default())
+                    .and_then(|score| sum.checked_add(score))
+            })
+            .ok_or("the similarity score overflows")
+    }
+
+    /// `size` pairs of location IDs, where the right list repeats some IDs
+    /// from the left one.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let left = (0..size)
+            .map(|_| rng.range(10000..=99999))
+            .collect::<Vec<_>>();
+        let input = left
+            .iter()
+            .map(|l| {
+                let r = if rng.chance(0.3) {
+                    *rng.choose(&left)
+                } else {
+                    rng.range(10000..=99999)
+                };
+                format!("{}   {}\n", l, r)
+            })
+            .collect::<String>();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day01;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day01,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day01, &Part::BOTH, &[1, 100]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day01,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
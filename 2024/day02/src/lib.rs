@@ -0,0 +1,179 @@
+use aoc::{
+    generate::{Generated, Rng},
+    parse, ParseError, Part, Solution,
+};
+
+fn is_safe_part1(ints: &[i64]) -> bool {
+    ints.windows(2)
+        .map(|w| {
+            let diff = w[1] - w[0];
+            if diff.abs() <= 3 {
+                diff
+            } else {
+                0
+            }
+        })
+        .reduce(|l, r| {
+            if l.signum() == r.signum() {
+                l.signum() * l.abs().max(r.abs())
+            } else {
+                0
+            }
+        })
+        .is_none_or(|diff| diff != 0)
+}
+
+fn pair_is_unsafe(diff: i64, signum: i64) -> bool {
+    diff == 0 || diff.abs() > 3 || diff.signum() != signum
+}
+
+fn part2_impl(first: i64, second: i64, tail: &[i64], signum: i64, has_skipped: bool) -> bool {
+    if tail.is_empty() {
+        return !(has_skipped && pair_is_unsafe(second - first, signum));
+    }
+
+    if pair_is_unsafe(second - first, signum) || pair_is_unsafe(tail[0] - second, signum) {
+        if has_skipped {
+            false
+        } else {
+            part2_impl(first, tail[0], &tail[1..], signum, true)
+                || part2_impl(first, second, &tail[1..], signum, true)
+        }
+    } else {
+        part2_impl(second, tail[0], &tail[1..], signum, has_skipped)
+    }
+}
+
+fn is_safe_part2(ints: &[i64]) -> bool {
+    // Too short to tell the direction from the first steps.
+    if ints.len() < 4 {
+        return ints.len() < 3
+            || (0..3).any(|skip| is_safe_slow(&[&ints[..skip], &ints[skip + 1..]].concat()));
+    }
+    let peek = ints
+        .windows(2)
+        .take(3)
+        .map(|w| w[1] - w[0])
+        .collect::<Vec<i64>>();
+
+    let signum = if peek.iter().filter(|&&i| i > 0).count() > 1 {
+        1
+    } else if peek.iter().filter(|&&i| i < 0).count() > 1 {
+        -1
+    } else {
+        return false;
+    };
+
+    part2_impl(ints[1], ints[2], &ints[3..], signum, true)
+        || part2_impl(ints[0], ints[1], &ints[2..], signum, false)
+}
+
+fn is_safe_slow(report: &[i64]) -> bool {
+    let steps = |sign: i64| {
+        report
+            .windows(2)
+            .all(|w| (1..=3).contains(&(sign * (w[1] - w[0]))))
+    };
+    steps(1) || steps(-1)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Parsed<'a> = Vec<Vec<i64>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        parse::lines(input)
+            .map(|l| {
+                if l.trim().is_empty() {
+                    return Err(ParseError::new(input, l, "a report"));
+                }
+                // Levels are small enough that steps between them never overflow.
+                l.split_ascii_whitespace()
+                    .map(|i| parse::number::<i32>(input, i).map(i64::from))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(&self, reports: &Vec<Vec<i64>>) -> usize {
+        reports.iter().filter(|l| is_safe_part1(l)).count()
+    }
+
+    fn part2(&self, reports: &Vec<Vec<i64>>) -> usize {
+        reports.iter().filter(|l| is_safe_part2(l)).count()
+    }
+
+    /// `size` reports of 5 to 8 levels, mostly safe ones with one or two
+    /// levels changed. The answers come from checking every report the slow
+    /// way.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let reports = (0..size)
+            .map(|_| {
+                let direction = *rng.choose(&[-1, 1]);
+                let mut report = vec![rng.range(30..=70)];
+                for _ in 1..rng.range(5..=8) {
+                    report.push(report.last().unwrap() + direction * rng.range(1..=3));
+                }
+                for _ in 0..rng.index(3) {
+                    let index = rng.index(report.len());
+                    report[index] += rng.range(-4..=4);
+                }
+                report
+            })
+            .collect::<Vec<_>>();
+        let input = reports
+            .iter()
+            .map(|report| {
+                let levels = report.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+                levels.join(" ") + "\n"
+            })
+            .collect::<String>();
+        let part1 = reports.iter().filter(|r| is_safe_slow(r)).count();
+        let part2 = reports
+            .iter()
+            .filter(|r| {
+                (0..r.len()).any(|skip| is_safe_slow(&[&r[..skip], &r[skip + 1..]].concat()))
+            })
+            .count();
+        Some(
+            Generated::new(input)
+                .known(Part::One, part1)
+                .known(Part::Two, part2),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day02;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day02,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day02, &Part::BOTH, &[1, 100]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day02,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
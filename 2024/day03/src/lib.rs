@@ -0,0 +1,164 @@
+use aoc::{
+    combinators::{unsigned, Cursor, Parser},
+    generate::{Generated, Rng},
+    ParseError, Part, Solution,
+};
+
+enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+/// The 1 to 3 digit operand of a `mul`.
+fn operand<'a>() -> impl Parser<'a, Output = u64> {
+    unsigned::<u64>().try_map(|n| match n {
+        0..1000 => Ok(n),
+        _ => Err("a number of up to 3 digits"),
+    })
+}
+
+fn instruction<'a>() -> impl Parser<'a, Output = Instruction> {
+    let mul = "mul("
+        .ignore_then(operand())
+        .then_ignore(",")
+        .then(operand())
+        .then_ignore(")");
+    mul.map(|(l, r)| Instruction::Mul(l, r))
+        .or("do()".map(|_| Instruction::Do))
+        .or("don't()".map(|_| Instruction::Dont))
+}
+
+/// Every well formed instruction in the corrupted memory, in order.
+fn instructions(input: &str) -> impl Iterator<Item = Instruction> + '_ {
+    let parser = instruction();
+    let mut cursor = Cursor::new(input);
+    std::iter::from_fn(move || loop {
+        if let Ok((instruction, next)) = parser.parse(cursor) {
+            cursor = next;
+            return Some(instruction);
+        }
+        let skip = cursor.rest().chars().next()?.len_utf8();
+        cursor = cursor.advance(skip);
+    })
+}
+
+/// Pieces of corrupted memory that are no instruction on their own nor next
+/// to any other piece.
+const JUNK: [&str; 16] = [
+    "mul(4*",
+    "mul[3,7]",
+    "mul ( 2 , 4 )",
+    "?(12,34)",
+    "don't",
+    "do",
+    "select(",
+    "from()",
+    "what()",
+    "mul(32,64]",
+    "#",
+    "!",
+    "@^",
+    "%&",
+    "{<",
+    "+-'",
+];
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Parsed<'a> = &'a str;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    // Corrupted memory is the point of the puzzle, anything goes.
+    fn parse<'a>(&self, input: &'a str) -> Result<&'a str, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(&self, &input: &&str) -> u64 {
+        instructions(input)
+            .map(|instruction| match instruction {
+                Instruction::Mul(l, r) => l * r,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    fn part2(&self, &input: &&str) -> u64 {
+        let mut enabled = true;
+        let mut agg = 0;
+        for instruction in instructions(input) {
+            match instruction {
+                Instruction::Mul(l, r) if enabled => agg += l * r,
+                Instruction::Mul(..) => {}
+                Instruction::Do => enabled = true,
+                Instruction::Dont => enabled = false,
+            }
+        }
+        agg
+    }
+
+    /// Corrupted memory of `size` fragments, which are instructions,
+    /// almost-instructions and junk, over lines of 200 fragments.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut input = String::new();
+        let (mut part1, mut part2, mut enabled) = (0, 0, true);
+        for index in 0..size {
+            match rng.index(10) {
+                0..=2 => {
+                    let (l, r) = (rng.range(1..=999), rng.range(1..=999));
+                    input += &format!("mul({},{})", l, r);
+                    part1 += l * r;
+                    part2 += if enabled { l * r } else { 0 };
+                }
+                3 => {
+                    enabled = rng.chance(0.5);
+                    input += if enabled { "do()" } else { "don't()" };
+                }
+                _ => input += *rng.choose(&JUNK),
+            }
+            if index % 200 == 199 {
+                input.push('\n');
+            }
+        }
+        input.push('\n');
+        Some(
+            Generated::new(input)
+                .known(Part::One, part1)
+                .known(Part::Two, part2),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day03;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day03,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day03, &Part::BOTH, &[1, 500]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day03,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            300,
+        );
+    }
+}
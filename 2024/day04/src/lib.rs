@@ -0,0 +1,119 @@
+use aoc::{
+    generate::{Generated, Rng},
+    parse, Grid, Neighbourhood, ParseError, Position, Solution,
+};
+
+const XMAS: &[u8] = b"XMAS";
+
+fn spells_xmas(puzzle: &Grid<u8>, start: Position, offset: Position) -> bool {
+    XMAS.iter()
+        .zip(0..)
+        .all(|(&b, k)| puzzle.get(start + offset * k) == Some(&b))
+}
+
+fn part1(puzzle: &Grid<u8>) -> usize {
+    puzzle
+        .positions()
+        .flat_map(|start| {
+            Neighbourhood::Moore
+                .offsets()
+                .iter()
+                .filter(move |&&offset| spells_xmas(puzzle, start, offset))
+        })
+        .count()
+}
+
+const MAS: &[u8] = b"MAS";
+const SAM: &[u8] = b"SAM";
+
+macro_rules! get {
+    ( $puzzle:expr,$x:expr,$y:expr ) => {
+        match $puzzle.get(Position { x: $x, y: $y }) {
+            Some(b) => *b,
+            None => return false,
+        }
+    };
+}
+
+fn is_xmas(puzzle: &Grid<u8>, Position { x, y }: Position) -> bool {
+    let nwtose = [
+        get!(puzzle, x - 1, y - 1),
+        get!(puzzle, x, y),
+        get!(puzzle, x + 1, y + 1),
+    ];
+    let netosw = [
+        get!(puzzle, x + 1, y - 1),
+        get!(puzzle, x, y),
+        get!(puzzle, x - 1, y + 1),
+    ];
+    (nwtose == MAS || nwtose == SAM) && (netosw == MAS || netosw == SAM)
+}
+
+fn part2(puzzle: &Grid<u8>) -> usize {
+    puzzle
+        .positions()
+        .filter(|&position| is_xmas(puzzle, position))
+        .count()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Parsed<'a> = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Grid<u8>, ParseError> {
+        parse::charset(input, "XMAS")?;
+        Grid::parse(input)
+    }
+
+    fn part1(&self, puzzle: &Grid<u8>) -> usize {
+        part1(puzzle)
+    }
+
+    fn part2(&self, puzzle: &Grid<u8>) -> usize {
+        part2(puzzle)
+    }
+
+    /// A `size` by `size` word search of random letters.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut puzzle = Grid::filled(size as i64, size as i64, b'X');
+        for position in puzzle.positions() {
+            puzzle[position] = *rng.choose(XMAS);
+        }
+        Some(Generated::new(puzzle.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day04;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day04,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day04, &Part::BOTH, &[1, 20]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day04,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+use aoc::{
+    generate::{Generated, Rng},
+    parse, ParseError, Solution,
+};
+
+fn parse_list(input: &str, list: &str) -> Result<Vec<usize>, ParseError> {
+    list.split(',')
+        .map(|n| parse::number::<u16>(input, n).map(usize::from))
+        .collect()
+}
+
+type Relations = HashMap<usize, HashSet<usize>>;
+
+fn add_relation(
+    input: &str,
+    mut comes_before: Relations,
+    relation: &str,
+) -> Result<Relations, ParseError> {
+    let (before, after) = parse::split_once(input, relation, "|")?;
+    comes_before
+        .entry(parse::number(input, after)?)
+        .or_default()
+        .insert(parse::number(input, before)?);
+    Ok(comes_before)
+}
+
+fn list_is_sorted(comes_before: &Relations, numbers: &[usize]) -> bool {
+    !numbers.iter().enumerate().any(|(idx, j)| {
+        comes_before.get(j).is_some_and(|before| {
+            numbers[idx + 1..]
+                .iter()
+                .any(|after| before.contains(after))
+        })
+    })
+}
+
+fn sorted(comes_before: &Relations, i: &usize, j: &usize) -> bool {
+    comes_before.get(j).is_some_and(|before| before.contains(i))
+}
+
+/// Whether the rules put the pages of an update in exactly one order, so
+/// that sorting by them is well defined: every two pages have a rule, one way
+/// only, and there are no cycles, which shows as two pages with as many
+/// pages before them.
+fn is_ordered(comes_before: &Relations, numbers: &[usize]) -> bool {
+    let mut before_counts = vec![false; numbers.len()];
+    numbers.iter().all(|i| {
+        let before = numbers
+            .iter()
+            .filter(|j| sorted(comes_before, j, i))
+            .count();
+        let after = numbers
+            .iter()
+            .filter(|j| sorted(comes_before, i, j))
+            .count();
+        before + after + 1 == numbers.len() && !std::mem::replace(&mut before_counts[before], true)
+    })
+}
+
+fn sort_list(comes_before: &Relations, mut numbers: Vec<usize>) -> Vec<usize> {
+    numbers.sort_by(|i, j| {
+        let sorted = |i, j| sorted(comes_before, i, j);
+
+        if sorted(i, j) {
+            Ordering::Less
+        } else if sorted(j, i) {
+            Ordering::Greater
+        } else {
+            // Parsing checked the rules, so this is a page and itself.
+            Ordering::Equal
+        }
+    });
+    numbers
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Parsed<'a> = (Relations, Vec<Vec<usize>>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<(Relations, Vec<Vec<usize>>), ParseError> {
+        let mut blocks = parse::blocks(input);
+        let relations = blocks.next().unwrap_or_default();
+        let lists = blocks.next().ok_or_else(|| {
+            ParseError::new(input, &input[input.len()..], "a blank line and page lists")
+        })?;
+
+        let comes_before = relations
+            .lines()
+            .try_fold(HashMap::new(), |acc, relation| {
+                add_relation(input, acc, relation)
+            })?;
+        let lists_parsed = lists
+            .lines()
+            .map(|list| match parse_list(input, list)? {
+                numbers if is_ordered(&comes_before, &numbers) => Ok(numbers),
+                _ => Err(ParseError::new(input, list, "pages the rules put in order")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((comes_before, lists_parsed))
+    }
+
+    fn part1(&self, (comes_before, lists_parsed): &(Relations, Vec<Vec<usize>>)) -> usize {
+        lists_parsed
+            .iter()
+            .filter(|numbers| list_is_sorted(comes_before, numbers))
+            .map(|numbers| numbers[numbers.len() / 2])
+            .sum::<usize>()
+    }
+
+    fn part2(&self, (comes_before, lists_parsed): &(Relations, Vec<Vec<usize>>)) -> usize {
+        lists_parsed
+            .iter()
+            .filter(|numbers| !list_is_sorted(comes_before, numbers))
+            .map(|numbers| sort_list(comes_before, numbers.clone()))
+            .map(|numbers| numbers[numbers.len() / 2])
+            .sum::<usize>()
+    }
+
+    /// Rules ordering 49 pages in every pair and `size` updates of an odd
+    /// number of pages, about half of them in order.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut order = (10..100).collect::<Vec<usize>>();
+        rng.shuffle(&mut order);
+        order.truncate(49);
+        let mut rules = order
+            .iter()
+            .enumerate()
+            .flat_map(|(index, before)| order[index + 1..].iter().map(move |after| (before, after)))
+            .map(|(before, after)| format!("{}|{}\n", before, after))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut rules);
+        let updates = (0..size).map(|_| {
+            let mut pages = order.clone();
+            rng.shuffle(&mut pages);
+            pages.truncate(2 * rng.index(10) + 5);
+            if rng.chance(0.5) {
+                pages.sort_by_key(|page| order.iter().position(|p| p == page));
+            }
+            let pages = pages.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+            pages.join(",") + "\n"
+        });
+        Some(Generated::new(
+            rules.concat() + "\n" + &updates.collect::<String>(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day05;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day05,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day05, &Part::BOTH, &[1, 50]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day05,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
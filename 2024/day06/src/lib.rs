@@ -0,0 +1,158 @@
+use std::{collections::HashSet, iter::successors};
+
+use aoc::{
+    animation::Recorder,
+    generate::{Generated, Rng},
+    parse,
+    render::{Colour, Layer, Render},
+    Direction, Grid, ParseError, Position, Solution,
+};
+
+/// The guard's position and heading at every step until it leaves the map.
+fn walk(puzzle: &Grid<u8>, start: Position) -> impl Iterator<Item = (Position, Direction)> + '_ {
+    successors(
+        Some((start, Direction::North)),
+        |&(current, direction)| match puzzle.get(current + direction)? {
+            b'#' => Some((current, direction.turn_right())),
+            _ => Some((current + direction, direction)),
+        },
+    )
+}
+
+const LOOP: &str = "the guard walks in a loop and never leaves";
+
+fn part1(puzzle: &Grid<u8>, start: Position) -> Result<usize, &'static str> {
+    // An obstacle outside the map never gets in the way.
+    if part2(puzzle, start, Position::new(-1, -1)) {
+        return Err(LOOP);
+    }
+    Ok(walk(puzzle, start)
+        .map(|(position, _)| position)
+        .collect::<HashSet<_>>()
+        .len())
+}
+
+fn part2(puzzle: &Grid<u8>, start: Position, obstacle: Position) -> bool {
+    let mut current = start;
+    let mut direction = Direction::North;
+    let mut acc = HashSet::<(Position, Direction)>::new();
+    acc.insert((current, direction));
+    while let Some(&b) = puzzle.get(current + direction) {
+        let next = current + direction;
+        if acc.contains(&(next, direction)) {
+            return true;
+        };
+        acc.insert((next, direction));
+        if next == obstacle || b == b'#' {
+            direction = direction.turn_right();
+        } else {
+            current = next;
+        }
+    }
+    false
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Parsed<'a> = (Grid<u8>, Position);
+    type Part1 = Result<usize, &'static str>;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<(Grid<u8>, Position), ParseError> {
+        parse::charset(input, ".#^")?;
+        parse::unique(input, input, '^')?;
+        let puzzle = Grid::parse(input)?;
+        match puzzle.find(b'^') {
+            Some(start) => Ok((puzzle, start)),
+            None => Err(ParseError::new(input, &input[input.len()..], "a guard `^`")),
+        }
+    }
+
+    fn part1(&self, (puzzle, start): &(Grid<u8>, Position)) -> Result<usize, &'static str> {
+        part1(puzzle, *start)
+    }
+
+    fn part2(&self, (puzzle, start): &(Grid<u8>, Position)) -> usize {
+        // oops, remember to --release
+        let mut agg = 0;
+        for (position, &b) in puzzle.iter() {
+            if b != b'^' && part2(puzzle, *start, position) {
+                agg += 1;
+            }
+        }
+        agg
+    }
+
+    fn animate(&self, (puzzle, start): &(Grid<u8>, Position), recorder: &mut Recorder) {
+        let mut visited = Vec::new();
+        let mut seen = HashSet::new();
+        // Stops once the guard is back where it was, if it walks in a loop.
+        for (position, direction) in walk(puzzle, *start).take_while(|&step| seen.insert(step)) {
+            visited.push(position);
+            let guard = match direction {
+                Direction::East => '>',
+                Direction::West => '<',
+                Direction::South => 'v',
+                _ => '^',
+            };
+            recorder.record(|| {
+                Render::map(puzzle)
+                    .layer(Layer::new(visited.iter().copied(), Colour::BLUE))
+                    .layer(Layer::new([position], Colour::RED).glyph(guard))
+            });
+        }
+    }
+
+    /// A `size` by `size` lab with scattered obstructions, which the guard
+    /// leaves without walking in a loop.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let side = size.max(2) as i64;
+        loop {
+            let mut puzzle = Grid::filled(side, side, b'.');
+            for position in puzzle.positions() {
+                if rng.chance(0.08) {
+                    puzzle[position] = b'#';
+                }
+            }
+            let start = Position::new(rng.range(0..=side - 1), rng.range(0..=side - 1));
+            puzzle[start] = b'^';
+            // An obstacle outside the map never gets in the way.
+            if !part2(&puzzle, start, Position::new(-1, -1)) {
+                return Some(Generated::new(puzzle.to_string()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day06;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day06,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day06, &Part::BOTH, &[5, 20]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day06,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use aoc::{
+    generate::{Generated, Rng},
+    parse, ParseError, Solution,
+};
+
+const BIT_MASK: [usize; 11] = [1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024];
+
+fn parse(input: &str, s: &str) -> Result<(usize, Vec<usize>), ParseError> {
+    let (target, raw_numbers) = parse::split_once(input, s, ": ")?;
+    let numbers = raw_numbers
+        .split(" ")
+        .map(|s| parse::number(input, s))
+        .collect::<Result<Vec<_>, _>>()?;
+    if numbers.len() > BIT_MASK.len() + 1 {
+        return Err(ParseError::new(
+            input,
+            raw_numbers,
+            format!("at most {} numbers", BIT_MASK.len() + 1),
+        ));
+    }
+    Ok((parse::number(input, target)?, numbers))
+}
+
+// An overflowing operator makes the equation false, as the numbers of real
+// inputs are positive and no operator makes the result smaller.
+fn could_be_true1((target, numbers): &(usize, Vec<usize>)) -> bool {
+    (0..2 << numbers.len()).any(|j| {
+        let acc =
+            numbers[1..]
+                .iter()
+                .zip(BIT_MASK.iter())
+                .try_fold(numbers[0], |acc, (number, mask)| {
+                    if j & mask == 0 {
+                        acc.checked_add(*number)
+                    } else {
+                        acc.checked_mul(*number)
+                    }
+                });
+        acc == Some(*target)
+    })
+}
+
+fn could_be_true2((target, numbers): (usize, &[usize]), current: Option<usize>) -> bool {
+    let Some(current) = current else {
+        return false;
+    };
+    if numbers.is_empty() {
+        current == target
+    } else {
+        could_be_true2((target, &numbers[1..]), current.checked_add(numbers[0]))
+            || could_be_true2((target, &numbers[1..]), current.checked_mul(numbers[0]))
+            || could_be_true2(
+                (target, &numbers[1..]),
+                (current.to_string() + &numbers[0].to_string()).parse().ok(),
+            )
+    }
+}
+
+fn calibration<'a>(mut targets: impl Iterator<Item = &'a usize>) -> Result<usize, &'static str> {
+    targets
+        .try_fold(0usize, |sum, target| sum.checked_add(*target))
+        .ok_or("the calibration result overflows")
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    type Parsed<'a> = Vec<(usize, Vec<usize>)>;
+    type Part1 = Result<usize, &'static str>;
+    type Part2 = Result<usize, &'static str>;
+
+    fn parse(&self, input: &str) -> Result<Vec<(usize, Vec<usize>)>, ParseError> {
+        parse::lines(input).map(|line| parse(input, line)).collect()
+    }
+
+    fn part1(&self, equations: &Vec<(usize, Vec<usize>)>) -> Result<usize, &'static str> {
+        calibration(
+            equations
+                .iter()
+                .filter(|equation| could_be_true1(equation))
+                .map(|(target, _)| target),
+        )
+    }
+
+    fn part2(&self, equations: &Vec<(usize, Vec<usize>)>) -> Result<usize, &'static str> {
+        calibration(
+            equations
+                .iter()
+                .filter(|(target, numbers)| {
+                    could_be_true2((*target, &numbers[1..]), Some(numbers[0]))
+                })
+                .map(|(target, _)| target),
+        )
+    }
+
+    /// `size` equations of 2 to 6 numbers below 1000, most of which some
+    /// choice of operators makes true. That keeps every way of combining
+    /// them within 18 digits.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let input = (0..size)
+            .map(|_| {
+                let numbers = (0..rng.range(2..=6))
+                    .map(|_| rng.range(1..=999) as usize)
+                    .collect::<Vec<_>>();
+                let mut target =
+                    numbers[1..]
+                        .iter()
+                        .fold(numbers[0], |acc, &n| match rng.index(3) {
+                            0 => acc + n,
+                            1 => acc * n,
+                            _ => (acc.to_string() + &n.to_string()).parse().unwrap(),
+                        });
+                if rng.chance(0.3) {
+                    target += 1;
+                }
+                let numbers = numbers.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+                format!("{}: {}\n", target, numbers.join(" "))
+            })
+            .collect::<String>();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day07;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day07,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day07, &Part::BOTH, &[1, 50]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day07,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+
+use aoc::{
+    generate::{Generated, Rng},
+    Grid, ParseError, Position, Solution,
+};
+
+fn insert_antinode(
+    set: &mut HashSet<Position>,
+    grid: &Grid<u8>,
+    first: Position,
+    second: Position,
+) -> Option<Position> {
+    let antinode = first * 2 - second;
+    if grid.contains(antinode) {
+        set.insert(antinode);
+        Some(antinode)
+    } else {
+        None
+    }
+}
+
+fn insert_antinodes(
+    set: &mut HashSet<Position>,
+    grid: &Grid<u8>,
+    mut first: Position,
+    mut second: Position,
+) {
+    set.insert(first);
+    set.insert(second);
+    while let Some(next) = insert_antinode(set, grid, first, second) {
+        second = first;
+        first = next;
+    }
+    while let Some(next) = insert_antinode(set, grid, second, first) {
+        first = second;
+        second = next;
+    }
+}
+
+fn antennae(grid: &Grid<u8>) -> impl Iterator<Item = (usize, Position, u8)> + '_ {
+    grid.iter()
+        .filter(|(_, &b)| b != b'.')
+        .enumerate()
+        .map(|(index, (position, &b))| (index, position, b))
+}
+
+fn antenna_pairs(grid: &Grid<u8>) -> impl Iterator<Item = (Position, Position)> + '_ {
+    antennae(grid).flat_map(move |(first, first_position, first_b)| {
+        antennae(grid)
+            .skip(first + 1)
+            .filter(move |&(_, _, second_b)| second_b == first_b)
+            .map(move |(_, second_position, _)| (first_position, second_position))
+    })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Parsed<'a> = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Grid<u8>, ParseError> {
+        if let Some(index) =
+            input.find(|c: char| !c.is_ascii_alphanumeric() && !".\r\n".contains(c))
+        {
+            return Err(ParseError::at_offset(input, index, "`.` or an antenna"));
+        }
+        Grid::parse(input)
+    }
+
+    fn part1(&self, grid: &Grid<u8>) -> usize {
+        let mut part1 = HashSet::new();
+        for (first, second) in antenna_pairs(grid) {
+            insert_antinode(&mut part1, grid, first, second);
+            insert_antinode(&mut part1, grid, second, first);
+        }
+        part1.len()
+    }
+
+    fn part2(&self, grid: &Grid<u8>) -> usize {
+        let mut part2 = HashSet::new();
+        for (first, second) in antenna_pairs(grid) {
+            insert_antinodes(&mut part2, grid, first, second);
+        }
+        part2.len()
+    }
+
+    /// A `size` by `size` map with an antenna on about one in 25 cells,
+    /// spread over 30 frequencies.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let frequencies = b"0123456789abcdefghijABCDEFGHIJ";
+        let mut grid = Grid::filled(size as i64, size as i64, b'.');
+        for position in grid.positions() {
+            if rng.chance(0.04) {
+                grid[position] = *rng.choose(frequencies);
+            }
+        }
+        Some(Generated::new(grid.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day08;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day08,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day08, &Part::BOTH, &[1, 30]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day08,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
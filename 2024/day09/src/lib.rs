@@ -0,0 +1,231 @@
+use std::cmp::min;
+
+use aoc::{
+    generate::{Generated, Rng},
+    ParseError, Solution,
+};
+
+#[derive(Debug)]
+struct Slot {
+    id: u64,
+    size: u64,
+}
+
+impl Slot {
+    fn new(id: u64, size: u64) -> Slot {
+        Slot { id, size }
+    }
+}
+
+// There is no free space after the last file.
+fn get(puzzle: &[u8], index: usize) -> u64 {
+    puzzle.get(index).map_or(0, |&b| (b - 48).into())
+}
+
+fn take_left(puzzle: &[u8], left_index: usize, left_id: u64) -> (usize, u64, u64) {
+    (left_index + 2, left_id + 1, get(puzzle, left_index + 1))
+}
+
+fn take_right(puzzle: &[u8], right_index: usize, right_id: u64) -> (usize, u64, u64) {
+    (right_index - 2, right_id - 1, get(puzzle, right_index))
+}
+
+// Block positions and IDs both grow with the length of the disk map, so the
+// checksum of a long enough one no longer fits.
+const OVERFLOW: &str = "the checksum overflows";
+
+fn checksum(start_index: u64, id: u64, size: u64) -> Option<u64> {
+    id.checked_mul(size * start_index + (size - 1) * size / 2)
+}
+
+fn part1(puzzle: &[u8]) -> Result<u64, &'static str> {
+    let mut left_index = 0;
+    let mut left_id = 0;
+    let mut to_fill = 0;
+
+    let mut right_index = puzzle.len() - 1;
+    let mut right_id = puzzle.len().div_ceil(2).try_into().unwrap();
+    let mut to_push = 0;
+
+    let mut disk = Vec::new();
+
+    while left_id < right_id {
+        if to_fill == 0 {
+            disk.push(Slot::new(left_id, get(puzzle, left_index)));
+            (left_index, left_id, to_fill) = take_left(puzzle, left_index, left_id);
+        } else if to_push == 0 {
+            (right_index, right_id, to_push) = take_right(puzzle, right_index, right_id);
+        } else {
+            let to = min(to_fill, to_push);
+            disk.push(Slot::new(right_id, to));
+            to_fill -= to;
+            to_push -= to;
+        }
+    }
+
+    if to_push > 0 {
+        disk.push(Slot::new(right_id, to_push));
+    }
+
+    let mut start_index = 0;
+    let mut agg = 0u64;
+    for slot in disk {
+        agg = checksum(start_index, slot.id, slot.size)
+            .and_then(|checksum| agg.checked_add(checksum))
+            .ok_or(OVERFLOW)?;
+        start_index += slot.size
+    }
+
+    Ok(agg)
+}
+
+#[derive(Debug)]
+struct Slot2 {
+    id: Option<u64>,
+    size: u64,
+}
+
+impl Slot2 {
+    fn new(id: Option<u64>, size: u64) -> Slot2 {
+        Slot2 { id, size }
+    }
+}
+
+fn start_disk(puzzle: &[u8]) -> Vec<Slot2> {
+    let mut disk = Vec::new();
+    let mut id = 0;
+    let mut index = 0;
+    disk.push(Slot2::new(Some(id), get(puzzle, index)));
+    index += 1;
+    id += 1;
+    while index < puzzle.len() - 1 {
+        let empty_size = get(puzzle, index);
+        if empty_size > 0 {
+            disk.push(Slot2::new(None, empty_size));
+        }
+        disk.push(Slot2::new(Some(id), get(puzzle, index + 1)));
+        id += 1;
+        index += 2;
+    }
+    disk
+}
+
+fn end_disk(mut disk: Vec<Slot2>) -> Vec<Slot2> {
+    let mut outcome = Vec::new();
+    while let Some(head) = disk.pop() {
+        let insert = head.id.and_then(|_| {
+            disk.iter()
+                .enumerate()
+                .find(|(_, slot)| slot.id.is_none() && slot.size >= head.size)
+                .map(|(index, slot)| (index, slot.size - head.size))
+        });
+        match insert {
+            None => outcome.push(head),
+            Some((index, empty_size)) => {
+                disk.push(Slot2::new(None, head.size));
+                disk[index] = head;
+                if empty_size > 0 {
+                    disk.insert(index + 1, Slot2::new(None, empty_size));
+                }
+            }
+        }
+    }
+    outcome.reverse();
+    outcome
+}
+
+fn part2(puzzle: &[u8]) -> Result<u64, &'static str> {
+    let disk = start_disk(puzzle);
+    let outcome = end_disk(disk);
+
+    outcome
+        .iter()
+        .scan(0, |state, slot| {
+            let start_index = *state;
+            *state += slot.size;
+            Some((start_index, slot))
+        })
+        .filter_map(|(start_index, slot)| slot.id.map(|id| (start_index, id, slot.size)))
+        .try_fold(0u64, |agg, (start_index, id, size)| {
+            agg.checked_add(checksum(start_index, id, size)?)
+        })
+        .ok_or(OVERFLOW)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    type Parsed<'a> = &'a [u8];
+    type Part1 = Result<u64, &'static str>;
+    type Part2 = Result<u64, &'static str>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<&'a [u8], ParseError> {
+        let disk_map = input.trim_end();
+        if let Some(index) = disk_map.find(|c: char| !c.is_ascii_digit()) {
+            return Err(ParseError::at_offset(input, index, "a digit"));
+        }
+        // Every other digit is a file, which takes up at least one block.
+        match disk_map.bytes().step_by(2).position(|b| b == b'0') {
+            Some(file) => Err(ParseError::at_offset(
+                input,
+                2 * file,
+                "a file size from 1 to 9",
+            )),
+            None if disk_map.is_empty() => Err(ParseError::new(input, disk_map, "a disk map")),
+            None => Ok(disk_map.as_bytes()),
+        }
+    }
+
+    fn part1(&self, disk_map: &&[u8]) -> Result<u64, &'static str> {
+        part1(disk_map)
+    }
+
+    fn part2(&self, disk_map: &&[u8]) -> Result<u64, &'static str> {
+        part2(disk_map)
+    }
+
+    /// A disk map of `size` files of 1 to 9 blocks with gaps of 0 to 9
+    /// blocks between them.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut disk_map = String::new();
+        for file in 0..size.max(1) {
+            if file > 0 {
+                disk_map += &rng.range(0..=9).to_string();
+            }
+            disk_map += &rng.range(1..=9).to_string();
+        }
+        Some(Generated::new(disk_map + "\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day09;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day09,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day09, &Part::BOTH, &[1, 2, 100]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day09,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
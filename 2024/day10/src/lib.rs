@@ -0,0 +1,124 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::{
+    generate::{Generated, Rng},
+    parse, Grid, ParseError, Position, Solution,
+};
+
+type Scores = HashMap<Position, HashSet<Position>>;
+type Ratings = HashMap<Position, u64>;
+
+fn get_highes_indices(puzzle: &Grid<u8>) -> impl Iterator<Item = Position> + '_ {
+    puzzle
+        .iter()
+        .filter(|(_, &height)| height == 9)
+        .map(|(index, _)| index)
+}
+
+fn next_indices(puzzle: &Grid<u8>, index: Position) -> impl Iterator<Item = Position> + '_ {
+    puzzle
+        .neighbours(index)
+        .map(|(_, next_index)| next_index)
+        .filter(move |&next_index| puzzle[index] == 1 + puzzle[next_index])
+}
+
+fn next_scores(puzzle: &Grid<u8>, scores: &Scores) -> Scores {
+    scores
+        .iter()
+        .flat_map(|(&k, v)| next_indices(puzzle, k).map(move |next_index| (next_index, v)))
+        .fold(Scores::new(), |mut new_state, (next_index, v)| {
+            new_state.entry(next_index).or_default().extend(v);
+            new_state
+        })
+}
+
+fn next_ratings(puzzle: &Grid<u8>, ratings: &Ratings) -> Ratings {
+    ratings
+        .iter()
+        .flat_map(|(&k, v)| next_indices(puzzle, k).map(move |next_index| (next_index, v)))
+        .fold(Ratings::new(), |mut new_state, (next_index, v)| {
+            *new_state.entry(next_index).or_default() += v;
+            new_state
+        })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    type Parsed<'a> = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Grid<u8>, ParseError> {
+        parse::charset(input, "0123456789")?;
+        Ok(Grid::parse(input)?.map(|b| b - b'0'))
+    }
+
+    fn part1(&self, puzzle: &Grid<u8>) -> usize {
+        let first_scores = get_highes_indices(puzzle).map(|index| (index, HashSet::from([index])));
+        (0..9)
+            .fold(first_scores.collect(), |acc, _| next_scores(puzzle, &acc))
+            .values()
+            .map(|s| s.len())
+            .sum::<usize>()
+    }
+
+    fn part2(&self, puzzle: &Grid<u8>) -> u64 {
+        let first_ratings = get_highes_indices(puzzle).map(|index| (index, 1));
+        (0..9)
+            .fold(first_ratings.collect(), |acc, _| next_ratings(puzzle, &acc))
+            .values()
+            .sum::<u64>()
+    }
+
+    /// A `size` by `size` map of random heights with gradual trails walked
+    /// over it from one in 20 cells.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let side = size.max(2) as i64;
+        let mut map = Grid::filled(side, side, b'0');
+        for position in map.positions() {
+            map[position] = b'0' + rng.index(10) as u8;
+        }
+        for _ in 0..side * side / 20 {
+            let mut position = Position::new(rng.range(0..=side - 1), rng.range(0..=side - 1));
+            for height in b'0'..=b'9' {
+                map[position] = height;
+                let next = map.neighbours(position).collect::<Vec<_>>();
+                position = rng.choose(&next).1;
+            }
+        }
+        Some(Generated::new(map.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day10;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day10,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day10, &Part::BOTH, &[2, 20]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day10,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
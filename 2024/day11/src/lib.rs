@@ -0,0 +1,207 @@
+use std::{
+    collections::{HashMap, HashSet},
+    iter::repeat,
+};
+
+use aoc::{
+    generate::{Generated, Rng},
+    parse, Cache, Param, ParseError, Solution,
+};
+
+mod post;
+
+pub use post::Day11Post;
+
+/// Stones below this never grow past 64 bits: an odd number of digits is
+/// multiplied by 2024 at most twice before the number splits in two.
+const MAX_STONE: u64 = 1_000_000_000_000;
+
+const OVERFLOW: &str = "the number of stones overflows";
+
+fn parse_stones(input: &str) -> Result<Vec<u64>, ParseError> {
+    input
+        .split_whitespace()
+        .map(|s| match parse::number(input, s)? {
+            stone if stone < MAX_STONE => Ok(stone),
+            _ => Err(ParseError::new(input, s, "a number of up to 12 digits")),
+        })
+        .collect()
+}
+
+fn count_part1(
+    i: u64,
+    left: u32,
+    count_part1: &mut dyn FnMut(&(u64, u32)) -> Option<u64>,
+) -> Option<u64> {
+    if left == 0 {
+        Some(1)
+    } else if i == 0 {
+        count_part1(&(1, left - 1))
+    } else {
+        let i_string = i.to_string();
+        let i_len = i_string.len();
+        if i_len.is_multiple_of(2) {
+            count_part1(&(i_string[..i_len / 2].parse().unwrap(), left - 1))?.checked_add(
+                count_part1(&(i_string[i_len / 2..].parse().unwrap(), left - 1))?,
+            )
+        } else {
+            count_part1(&(i * 2024, left - 1))
+        }
+    }
+}
+
+fn next_elements(element: u64) -> Vec<u64> {
+    if element == 0 {
+        vec![1]
+    } else {
+        let element_string = element.to_string();
+        let element_len = element_string.len();
+        if element_len.is_multiple_of(2) {
+            vec![
+                element_string[..element_len / 2].parse().unwrap(),
+                element_string[element_len / 2..].parse().unwrap(),
+            ]
+        } else {
+            vec![element * 2024]
+        }
+    }
+}
+
+fn add_elements(mut space: HashSet<u64>, &element: &u64) -> HashSet<u64> {
+    let mut elems = vec![element];
+    while !elems.is_empty() {
+        for &elem in &elems {
+            space.insert(elem);
+        }
+        elems = elems
+            .iter()
+            .flat_map(|&elem| next_elements(elem))
+            .filter(|elem| !space.contains(elem))
+            .collect::<Vec<_>>();
+    }
+    space
+}
+
+fn next_counts(acc: HashMap<u64, u64>, space: &HashSet<u64>) -> Option<HashMap<u64, u64>> {
+    space
+        .iter()
+        .map(|&i| {
+            let count = next_elements(i)
+                .iter()
+                .try_fold(0u64, |count, next_element| {
+                    count.checked_add(acc[next_element])
+                })?;
+            Some((i, count))
+        })
+        .collect()
+}
+
+fn random_stones(rng: &mut Rng, size: usize) -> Generated {
+    let stones = (0..size.max(1))
+        .map(|_| {
+            let digits = rng.range(1..=6) as u32;
+            rng.range(0..=10i64.pow(digits) - 1).to_string()
+        })
+        .collect::<Vec<_>>();
+    Generated::new(stones.join(" ") + "\n")
+}
+
+pub struct Day11 {
+    pub blinks_part1: u32,
+    pub blinks_part2: u32,
+}
+
+impl Default for Day11 {
+    fn default() -> Day11 {
+        Day11 {
+            blinks_part1: 25,
+            blinks_part2: 75,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type Parsed<'a> = Vec<u64>;
+    type Part1 = Result<u64, &'static str>;
+    type Part2 = Result<u64, &'static str>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>, ParseError> {
+        parse_stones(input)
+    }
+
+    fn part1(&self, parsed: &Vec<u64>) -> Result<u64, &'static str> {
+        let mut cache = Cache::new(
+            |&(i, left): &(u64, u32), recurse: &mut dyn FnMut(&(u64, u32)) -> Option<u64>| {
+                count_part1(i, left, recurse)
+            },
+        );
+        parsed
+            .iter()
+            .try_fold(0u64, |sum, &i| {
+                sum.checked_add(cache.get_or_compute(&(i, self.blinks_part1))?)
+            })
+            .ok_or(OVERFLOW)
+    }
+
+    fn part2(&self, parsed: &Vec<u64>) -> Result<u64, &'static str> {
+        let space = parsed.iter().fold(HashSet::new(), add_elements);
+        let first = space.iter().cloned().zip(repeat(1)).collect();
+        let counts = (0..self.blinks_part2)
+            .try_fold(first, |acc, _| next_counts(acc, &space))
+            .ok_or(OVERFLOW)?;
+        parsed
+            .iter()
+            .try_fold(0u64, |sum, i| sum.checked_add(counts[i]))
+            .ok_or(OVERFLOW)
+    }
+
+    fn params_mut(&mut self) -> Vec<(&'static str, &mut dyn Param)> {
+        vec![
+            ("blinks_part1", &mut self.blinks_part1),
+            ("blinks_part2", &mut self.blinks_part2),
+        ]
+    }
+
+    /// `size` stones with numbers of up to 6 digits.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(random_stones(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Part, Solver};
+
+    use crate::{Day11, Day11Post};
+
+    #[test]
+    fn test_examples() {
+        let examples = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+        aoc::examples::assert_examples(&mut Day11::default(), examples);
+        aoc::examples::assert_examples(&mut Day11Post::default(), examples);
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day11::default(), &Part::BOTH, &[1, 10]);
+        for seed in 0..3 {
+            let input = Day11::default().generate(seed, 50).unwrap().input;
+            assert_eq!(
+                Day11::default().solve(&input, &Part::BOTH),
+                Day11Post::default().solve(&input, &Part::BOTH),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_fuzz() {
+        let examples = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+        aoc::fuzz::assert_total(&mut Day11::default(), examples, 10);
+        aoc::fuzz::assert_total(&mut Day11Post::default(), examples, 10);
+    }
+}
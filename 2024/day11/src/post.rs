@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use aoc::{
+    generate::{Generated, Rng},
+    Param, ParseError, Solution,
+};
+
+fn count_part1(i: u64, left: u32) -> Option<u64> {
+    if left == 0 {
+        Some(1)
+    } else if i == 0 {
+        count_part1(1, left - 1)
+    } else {
+        let i_string = i.to_string();
+        let i_len = i_string.len();
+        if i_len.is_multiple_of(2) {
+            count_part1(i_string[..i_len / 2].parse().unwrap(), left - 1)?.checked_add(count_part1(
+                i_string[i_len / 2..].parse().unwrap(),
+                left - 1,
+            )?)
+        } else {
+            count_part1(i * 2024, left - 1)
+        }
+    }
+}
+
+fn next_elements(element: u64) -> Vec<u64> {
+    if element == 0 {
+        vec![1]
+    } else {
+        let element_string = element.to_string();
+        let element_len = element_string.len();
+        if element_len.is_multiple_of(2) {
+            vec![
+                element_string[..element_len / 2].parse().unwrap(),
+                element_string[element_len / 2..].parse().unwrap(),
+            ]
+        } else {
+            vec![element * 2024]
+        }
+    }
+}
+
+fn next_counts(acc: &HashMap<u64, u64>) -> Option<HashMap<u64, u64>> {
+    let mut next_acc = HashMap::new();
+    for (&i, &v) in acc {
+        for next_element in next_elements(i) {
+            let count: &mut u64 = next_acc.entry(next_element).or_default();
+            *count = count.checked_add(v)?;
+        }
+    }
+    Some(next_acc)
+}
+
+pub struct Day11Post {
+    pub blinks_part1: u32,
+    pub blinks_part2: u32,
+}
+
+impl Default for Day11Post {
+    fn default() -> Day11Post {
+        Day11Post {
+            blinks_part1: 25,
+            blinks_part2: 75,
+        }
+    }
+}
+
+impl Solution for Day11Post {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+    const VARIANT: &'static str = "counts";
+
+    type Parsed<'a> = Vec<u64>;
+    type Part1 = Result<u64, &'static str>;
+    type Part2 = Result<u64, &'static str>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>, ParseError> {
+        crate::parse_stones(input)
+    }
+
+    fn part1(&self, parsed: &Vec<u64>) -> Result<u64, &'static str> {
+        parsed
+            .iter()
+            .try_fold(0u64, |sum, &i| {
+                sum.checked_add(count_part1(i, self.blinks_part1)?)
+            })
+            .ok_or(crate::OVERFLOW)
+    }
+
+    fn part2(&self, parsed: &Vec<u64>) -> Result<u64, &'static str> {
+        let initial_counts = parsed.iter().fold(HashMap::new(), |mut acc, &i| {
+            *acc.entry(i).or_default() += 1;
+            acc
+        });
+        (0..self.blinks_part2)
+            .try_fold(initial_counts, |acc, _| next_counts(&acc))
+            .and_then(|counts| {
+                counts
+                    .values()
+                    .try_fold(0u64, |sum, &count| sum.checked_add(count))
+            })
+            .ok_or(crate::OVERFLOW)
+    }
+
+    fn params_mut(&mut self) -> Vec<(&'static str, &mut dyn Param)> {
+        vec![
+            ("blinks_part1", &mut self.blinks_part1),
+            ("blinks_part2", &mut self.blinks_part2),
+        ]
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(crate::random_stones(rng, size))
+    }
+}
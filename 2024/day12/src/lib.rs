@@ -0,0 +1,166 @@
+use std::{collections::HashSet, iter::from_fn};
+
+use aoc::{
+    generate::{Generated, Rng},
+    tc_dist, Direction, Grid, Neighbourhood, ParseError, Position, Solution,
+};
+
+type Positions = HashSet<Position>;
+
+fn get_unvisited(visited: &Positions, seen: &Positions) -> Option<Position> {
+    seen.iter().find(|p| !visited.contains(p)).cloned()
+}
+
+fn region_chunks(puzzle: &Grid<u8>) -> impl FnMut() -> Option<Positions> + '_ {
+    let mut visited = Positions::new();
+    let mut points = puzzle.positions();
+    move || {
+        points.find(|p| !visited.contains(p)).map(|position| {
+            let plant = puzzle[position];
+            let mut positions = Positions::from([position]);
+            while let Some(position) = get_unvisited(&visited, &positions) {
+                visited.insert(position);
+                for (_, neighbor) in puzzle.neighbours(position) {
+                    if puzzle[neighbor] == plant {
+                        positions.insert(neighbor);
+                    }
+                }
+            }
+            positions
+        })
+    }
+}
+
+fn get_perimeter(region: &Positions) -> usize {
+    region
+        .iter()
+        .map(|&position| {
+            4 - Neighbourhood::VonNeumann
+                .around(position)
+                .filter(|n| region.contains(n))
+                .count()
+        })
+        .sum::<usize>()
+}
+
+fn pop_adjacent(
+    side: &HashSet<(Position, Direction)>,
+    separate_sides: &mut Vec<(Position, Direction)>,
+) -> Option<(Position, Direction)> {
+    separate_sides
+        .iter()
+        .position(|&(position, s)| {
+            side.contains(&(position + s.turn_left(), s))
+                || side.contains(&(position + s.turn_right(), s))
+        })
+        .map(|index| separate_sides.remove(index))
+}
+
+fn get_sides(positions: &Positions) -> usize {
+    let mut separate_sides = positions
+        .iter()
+        .flat_map(|&p| {
+            Direction::CARDINAL
+                .into_iter()
+                .filter(move |&side| !positions.contains(&(p + side)))
+                .map(move |side| (p, side))
+        })
+        .collect::<Vec<_>>();
+
+    let mut connected_sides = 0;
+    while let Some(separate_side) = separate_sides.pop() {
+        let mut connected_side = HashSet::new();
+        connected_side.insert(separate_side);
+        while let Some(adjacent_side) = pop_adjacent(&connected_side, &mut separate_sides) {
+            connected_side.insert(adjacent_side);
+        }
+        connected_sides += 1;
+    }
+    connected_sides
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+
+    type Parsed<'a> = Vec<Positions>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Positions>, ParseError> {
+        if let Some(index) = input.find(|c: char| !c.is_ascii_uppercase() && !"\r\n".contains(c)) {
+            return Err(ParseError::at_offset(input, index, "a plant type `A`-`Z`"));
+        }
+        let puzzle = Grid::parse(input)?;
+        Ok(from_fn(region_chunks(&puzzle)).collect())
+    }
+
+    fn part1(&self, regions: &Vec<Positions>) -> usize {
+        regions
+            .iter()
+            .map(|region| get_perimeter(region) * region.len())
+            .sum::<usize>()
+    }
+
+    fn part2(&self, regions: &Vec<Positions>) -> usize {
+        regions
+            .iter()
+            .map(|region| get_sides(region) * region.len())
+            .sum::<usize>()
+    }
+
+    /// A `size` by `size` garden where each plot takes the plant of the
+    /// nearest of a few seeded plots, with the odd stray plant.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let side = size.max(1) as i64;
+        let seeds = (0..(side * side / 20).max(1))
+            .map(|_| {
+                let position = Position::new(rng.range(0..=side - 1), rng.range(0..=side - 1));
+                (position, b'A' + rng.index(26) as u8)
+            })
+            .collect::<Vec<_>>();
+        let mut garden = Grid::filled(side, side, b'A');
+        for position in garden.positions() {
+            garden[position] = if rng.chance(0.02) {
+                b'A' + rng.index(26) as u8
+            } else {
+                let nearest = seeds
+                    .iter()
+                    .min_by_key(|(seed, _)| tc_dist(*seed, position));
+                nearest.unwrap().1
+            };
+        }
+        Some(Generated::new(garden.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day12;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day12,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day12, &Part::BOTH, &[1, 20]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day12,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
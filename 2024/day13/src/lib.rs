@@ -0,0 +1,194 @@
+use std::cmp::min;
+
+use aoc::{
+    combinators::{blocks, line_break, unsigned, Parser},
+    generate::{Generated, Rng},
+    Param, ParseError, Part, Solution,
+};
+
+type Position = (isize, isize);
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    a: Position,
+    b: Position,
+    prize: Position,
+}
+
+/// A coordinate, small enough that no product of two overflows.
+fn coordinate<'a>() -> impl Parser<'a, Output = isize> {
+    unsigned::<u32>().map(|n| n as isize)
+}
+
+/// `X<sign>x, Y<sign>y`.
+fn xy<'a>(sign: &'static str) -> impl Parser<'a, Output = Position> {
+    "X".ignore_then(sign)
+        .ignore_then(coordinate())
+        .then_ignore(", Y")
+        .then_ignore(sign)
+        .then(coordinate())
+}
+
+fn parse(puzzle: &str) -> Result<Vec<Input>, ParseError> {
+    let machine = "Button A: "
+        .ignore_then(xy("+"))
+        .then_ignore(line_break())
+        .then_ignore("Button B: ")
+        .then(xy("+"))
+        .then_ignore(line_break())
+        .then_ignore("Prize: ")
+        .then(xy("="))
+        .map(|((a, b), prize)| Input { a, b, prize });
+    blocks(machine).parse_all(puzzle)
+}
+
+fn cheapest(input: &Input) -> Option<isize> {
+    let mut min_price = None;
+    for a in 0..=100 {
+        for b in 0..=100 {
+            if input.prize == (a * input.a.0 + b * input.b.0, a * input.a.1 + b * input.b.1) {
+                min_price = min_price.map(|c| min(c, a * 3 + b)).or(Some(a * 3 + b));
+            }
+        }
+    }
+    min_price
+}
+
+/// The tokens to win the prize moved by `offset`, if it can be won, by
+/// Cramer's rule. That needs the buttons to move in different directions,
+/// as they do in every real machine. The arithmetic is wide enough for any
+/// offset.
+fn direct(input: &Input, offset: isize) -> Result<Option<i128>, &'static str> {
+    let a = (input.a.0 as i128, input.a.1 as i128);
+    let b = (input.b.0 as i128, input.b.1 as i128);
+    let prize = (
+        input.prize.0 as i128 + offset as i128,
+        input.prize.1 as i128 + offset as i128,
+    );
+
+    let det = a.0 * b.1 - a.1 * b.0;
+    if det == 0 {
+        return Err("the buttons of a machine move in the same direction");
+    }
+    let x_numerator = prize.0 * b.1 - prize.1 * b.0;
+    let y_numerator = prize.1 * a.0 - prize.0 * a.1;
+
+    if x_numerator % det != 0 || y_numerator % det != 0 {
+        Ok(None)
+    } else {
+        let (x, y) = (x_numerator / det, y_numerator / det);
+        Ok((x >= 0 && y >= 0).then_some(3 * x + y))
+    }
+}
+
+pub struct Day13 {
+    pub offset: isize,
+}
+
+impl Default for Day13 {
+    fn default() -> Day13 {
+        Day13 {
+            offset: 10000000000000,
+        }
+    }
+}
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+
+    type Parsed<'a> = Vec<Input>;
+    type Part1 = isize;
+    type Part2 = Result<isize, &'static str>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Input>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Vec<Input>) -> isize {
+        parsed.iter().filter_map(cheapest).sum::<isize>()
+    }
+
+    fn part2(&self, parsed: &Vec<Input>) -> Result<isize, &'static str> {
+        let mut tokens = 0i128;
+        for input in parsed {
+            tokens += direct(input, self.offset)?.unwrap_or(0);
+        }
+        tokens
+            .try_into()
+            .map_err(|_| "the number of tokens overflows")
+    }
+
+    fn params_mut(&mut self) -> Vec<(&'static str, &mut dyn Param)> {
+        vec![("offset", &mut self.offset)]
+    }
+
+    /// `size` machines with independent buttons, two in three of which
+    /// reach their prize within 100 presses of each.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut machines = Vec::new();
+        let mut part1 = 0;
+        while machines.len() < size.max(1) {
+            let mut button = || (rng.range(10..=99) as isize, rng.range(10..=99) as isize);
+            let (a, b) = (button(), button());
+            let det = a.0 * b.1 - a.1 * b.0;
+            if det == 0 {
+                continue;
+            }
+            let prize = if rng.index(3) < 2 {
+                let (na, nb) = (rng.range(0..=100) as isize, rng.range(0..=100) as isize);
+                part1 += 3 * na + nb;
+                (na * a.0 + nb * b.0, na * a.1 + nb * b.1)
+            } else {
+                let prize = (
+                    rng.range(1000..=20000) as isize,
+                    rng.range(1000..=20000) as isize,
+                );
+                let na = prize.0 * b.1 - prize.1 * b.0;
+                let nb = prize.1 * a.0 - prize.0 * a.1;
+                if na % det == 0
+                    && nb % det == 0
+                    && (0..=100).contains(&(na / det))
+                    && (0..=100).contains(&(nb / det))
+                {
+                    continue;
+                }
+                prize
+            };
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            ));
+        }
+        Some(Generated::new(machines.join("\n")).known(Part::One, part1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day13;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day13::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day13::default(), &Part::BOTH, &[1, 20]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day13::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use aoc::{
+    animation::Recorder,
+    combinators::{field, lines, signed, Parser},
+    generate::{Generated, Rng},
+    render::{Cell, Colour, Layer, Render},
+    Grid, Param, ParseError, Part, Solution,
+};
+
+type Position = (isize, isize);
+type Velocity = (isize, isize);
+
+fn parse(input: &str) -> Result<Vec<(Position, Velocity)>, ParseError> {
+    // Small enough to move for as many seconds as there are in a cycle.
+    let number = || signed::<i32>().map(|n| n as isize);
+    let pair = || number().then_ignore(",").then(number());
+    let robot = field("p", pair()).then_ignore(" ").then(field("v", pair()));
+    lines(robot).parse_all(input)
+}
+
+fn normalize(i: isize, l: isize) -> isize {
+    let mut rem = i % l;
+    if rem < 0 {
+        rem += l
+    }
+    rem
+}
+
+fn elapse(seconds: isize, (w, t): (isize, isize)) -> impl Fn((Position, Velocity)) -> Position {
+    move |(position, velocity)| {
+        (
+            normalize(position.0 + seconds * velocity.0, w),
+            normalize(position.1 + seconds * velocity.1, t),
+        )
+    }
+}
+
+type QuadrantCount = [isize; 4];
+
+fn split_quadrants((w, t): (isize, isize)) -> impl Fn(QuadrantCount, Position) -> QuadrantCount {
+    move |mut acc, position| {
+        if position.0 < w / 2 && position.1 < t / 2 {
+            acc[0] += 1
+        } else if position.0 > w / 2 && position.1 < t / 2 {
+            acc[1] += 1
+        } else if position.0 < w / 2 && position.1 > t / 2 {
+            acc[2] += 1
+        } else if position.0 > w / 2 && position.1 > t / 2 {
+            acc[3] += 1
+        }
+        acc
+    }
+}
+
+type Screen = Vec<u8>;
+
+fn new_screen((w, t): (isize, isize)) -> Screen {
+    let mut screen = vec![b'.'; ((w + 1) * t) as usize];
+    for t in 0..t {
+        screen[(t * (w + 1) + w) as usize] = b'\n'
+    }
+    screen
+}
+
+fn build_screen(w: isize) -> impl Fn(Screen, Position) -> Screen {
+    move |mut screen, position| {
+        screen[(position.0 + position.1 * (w + 1)) as usize] = b'*';
+        screen
+    }
+}
+
+const MAX_SIDE: isize = 1000;
+
+pub struct Day14 {
+    pub width: isize,
+    pub height: isize,
+}
+
+impl Default for Day14 {
+    fn default() -> Day14 {
+        Day14 {
+            width: 101,
+            height: 103,
+        }
+    }
+}
+
+impl Day14 {
+    fn find_tree(&self, robots: &[(Position, Velocity)]) -> Option<isize> {
+        let room = (self.width, self.height);
+        // The robots are back where they started after width * height seconds.
+        (0..self.width * self.height).find(|&seconds| {
+            let screen = robots
+                .iter()
+                .cloned()
+                .map(elapse(seconds, room))
+                .fold(new_screen(room), build_screen(self.width));
+            // ranges found by displaying screens for low values and finding
+            // high concentrations in width and height separately
+            let count = (35..=65)
+                .flat_map(|w| (30..=62).map(move |t| (w, t)))
+                .filter(|(w, t)| screen.get((w + t * (self.width + 1)) as usize) == Some(&b'*'))
+                .count();
+            count >= 300
+        })
+    }
+
+    fn room(&self, robots: &[(Position, Velocity)], seconds: isize) -> Render {
+        let positions = robots
+            .iter()
+            .cloned()
+            .map(elapse(seconds, (self.width, self.height)))
+            .map(|(x, y)| aoc::Position::new(x as i64, y as i64));
+        let room = Grid::new(self.width as i64, self.height as i64);
+        Render::new(&room, |_| Cell::new('.', Colour::DARK_GREY))
+            .layer(Layer::new(positions, Colour::GREEN).glyph('*'))
+    }
+}
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+
+    type Parsed<'a> = Vec<(Position, Velocity)>;
+    type Part1 = Result<isize, &'static str>;
+    type Part2 = Result<isize, String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<(Position, Velocity)>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, robots: &Vec<(Position, Velocity)>) -> Result<isize, &'static str> {
+        let room = (self.width, self.height);
+        robots
+            .iter()
+            .cloned()
+            .map(elapse(100, room))
+            .fold(QuadrantCount::default(), split_quadrants(room))
+            .into_iter()
+            .try_fold(1isize, isize::checked_mul)
+            .ok_or("the safety factor overflows")
+    }
+
+    fn part2(&self, robots: &Vec<(Position, Velocity)>) -> Result<isize, String> {
+        self.find_tree(robots)
+            .ok_or_else(|| format!("no Christmas tree in a {}x{} room", self.width, self.height))
+    }
+
+    fn params_mut(&mut self) -> Vec<(&'static str, &mut dyn Param)> {
+        vec![("width", &mut self.width), ("height", &mut self.height)]
+    }
+
+    /// A room of a million tiles takes as many seconds for the robots to
+    /// come back around, so that is about as big as part 2 can search.
+    fn check_params(&self) -> Result<(), String> {
+        match (1..=MAX_SIDE).contains(&self.width) && (1..=MAX_SIDE).contains(&self.height) {
+            true => Ok(()),
+            false => Err(format!(
+                "the room must be 1 to {} tiles on each side",
+                MAX_SIDE
+            )),
+        }
+    }
+
+    /// The room when the tree appears, or after 100 seconds without one.
+    fn render(&self, robots: &Vec<(Position, Velocity)>) -> Option<Render> {
+        let seconds = self.find_tree(robots).unwrap_or(100);
+        Some(self.room(robots, seconds))
+    }
+
+    /// Every second up to the tree, or up to 100 seconds without one.
+    fn animate(&self, robots: &Vec<(Position, Velocity)>, recorder: &mut Recorder) {
+        for seconds in 0..=self.find_tree(robots).unwrap_or(100) {
+            recorder.record(|| self.room(robots, seconds));
+        }
+    }
+
+    /// A Christmas tree of robots that forms at a random second after the
+    /// first 100, the known answer to part 2, among `size` robots wandering
+    /// at random. There are at most 1500 of those so that the tree stands
+    /// out, and the room has to be big enough to hold it.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let (w, t) = (self.width, self.height);
+        if w <= 65 || t <= 62 {
+            return None;
+        }
+        let seconds = rng.range(101..=(w * t - 1) as i64) as isize;
+        let tree = (0..30)
+            .flat_map(|row| (-(row / 2)..=row / 2).map(move |dx| (50 + dx, 31 + row)))
+            .chain((49..=51).flat_map(|x| [(x, 61), (x, 62)]));
+        let velocity = |rng: &mut Rng| {
+            let mut speed = || {
+                let speed = rng.range(1..=99) as isize;
+                *rng.choose(&[-speed, speed])
+            };
+            (speed(), speed())
+        };
+        let mut robots = Vec::new();
+        for (x, y) in tree {
+            let velocity = velocity(rng);
+            let position = (
+                normalize(x - seconds * velocity.0, w),
+                normalize(y - seconds * velocity.1, t),
+            );
+            robots.push((position, velocity));
+        }
+        for _ in 0..size.min(1500) {
+            let position = (rng.range(0..=w as i64 - 1), rng.range(0..=t as i64 - 1));
+            robots.push(((position.0 as isize, position.1 as isize), velocity(rng)));
+        }
+        rng.shuffle(&mut robots);
+        let input = robots
+            .iter()
+            .map(|((x, y), (vx, vy))| format!("p={},{} v={},{}\n", x, y, vx, vy))
+            .collect::<String>();
+        Some(Generated::new(input).known(Part::Two, seconds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Part, Solver};
+
+    use crate::Day14;
+
+    #[test]
+    fn test_params() {
+        let mut day = Day14::default();
+        assert!(day.set_param("width", "0").is_err());
+        assert!(day.set_param("height", "1001").is_err());
+        assert_eq!(
+            day.params(),
+            [("width", "101".to_string()), ("height", "103".to_string())]
+        );
+        assert!(day.set_param("width", "11").is_ok());
+
+        // The example's parameters give way to ones the checks would reject.
+        let mut day = Day14 {
+            width: 0,
+            ..Day14::default()
+        };
+        let examples = aoc::examples::load(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+        assert!(aoc::examples::check(&mut day, &examples.unwrap()[0]).is_empty());
+        assert_eq!(day.width, 0);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day14::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day14::default(), &Part::BOTH, &[0, 200]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day14::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
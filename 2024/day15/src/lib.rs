@@ -0,0 +1,262 @@
+use std::{collections::HashMap, iter::successors};
+
+use aoc::{
+    animation::Recorder,
+    generate::{Generated, Rng},
+    parse,
+    render::{self, Cell, Colour, Render},
+    Direction, Grid, ParseError, Position, Solution,
+};
+
+type Moves = HashMap<Position, (u8, u8)>;
+
+fn parse(puzzle: &str) -> Result<(&str, Vec<Direction>), ParseError> {
+    let mut blocks = parse::blocks(puzzle);
+    let raw_map = blocks.next().unwrap_or_default();
+    let raw_directions = blocks.next().ok_or_else(|| {
+        ParseError::new(puzzle, &puzzle[puzzle.len()..], "a blank line and moves")
+    })?;
+    parse::charset(raw_map, "#O.@")?;
+    parse::unique(puzzle, raw_map, '@')?;
+    parse::charset(raw_directions, "^>v<")?;
+    let directions = raw_directions
+        .chars()
+        .filter_map(Direction::from_char)
+        .collect();
+    Ok((raw_map, directions))
+}
+
+fn parse2(raw_map: &str) -> String {
+    raw_map
+        .bytes()
+        .flat_map(|b| match b {
+            b'#' => vec!['#', '#'].into_iter(),
+            b'O' => vec!['[', ']'].into_iter(),
+            b'.' => vec!['.', '.'].into_iter(),
+            b'@' => vec!['@', '.'].into_iter(),
+            _ => vec![b as char].into_iter(),
+        })
+        .collect()
+}
+
+fn go(position: &Position, direction: &Direction, map: &Grid<u8>) -> Option<Position> {
+    Some(*position + *direction).filter(|&position| map.contains(position))
+}
+
+fn run_instruction(mut map: Grid<u8>, direction: &Direction) -> Grid<u8> {
+    let robot = map.find(b'@').unwrap();
+    let iterations = successors(Some(robot), |position| go(position, direction, &map))
+        .take_while(|&position| map[position] != b'#')
+        .position(|position| map[position] == b'.')
+        .map(|n| n + 1);
+
+    if let Some(n) = iterations {
+        let path = successors(Some(robot), |position| go(position, direction, &map))
+            .take(n)
+            .collect::<Vec<_>>();
+
+        map[path[0]] = b'.';
+        map[path[1]] = b'@';
+        path[2..].iter().for_each(|&position| map[position] = b'O');
+    }
+
+    map
+}
+
+fn move_ns<'a>(
+    map: &'a Grid<u8>,
+    direction: &'a Direction,
+) -> impl Fn(&Moves) -> Option<Moves> + 'a {
+    move |positions| {
+        positions
+            .iter()
+            .filter(|(_, (old, _))| *old != b'.')
+            .try_fold(
+                HashMap::new(),
+                |mut next_positions, (position, (old, _))| {
+                    let next_position = go(position, direction, map).unwrap();
+                    match map[next_position] {
+                        b'[' => {
+                            next_positions.entry(next_position).or_insert((b'[', *old));
+                            let e_pos = go(position, &Direction::East, map).unwrap();
+                            let e_val = positions.get(&e_pos).map(|b| b.0).unwrap_or(b'.');
+                            let next_e_pos = go(&next_position, &Direction::East, map).unwrap();
+                            next_positions.entry(next_e_pos).or_insert((b']', e_val));
+                            Some(next_positions)
+                        }
+                        b']' => {
+                            next_positions.entry(next_position).or_insert((b']', *old));
+                            let w_pos = go(position, &Direction::West, map).unwrap();
+                            let w_val = positions.get(&w_pos).map(|b| b.0).unwrap_or(b'.');
+                            let next_we_pos = go(&next_position, &Direction::West, map).unwrap();
+                            next_positions.entry(next_we_pos).or_insert((b'[', w_val));
+                            Some(next_positions)
+                        }
+                        b'.' => {
+                            next_positions.insert(next_position, (b'.', *old));
+                            Some(next_positions)
+                        }
+                        b'#' => None,
+                        _ => panic!(),
+                    }
+                },
+            )
+            .filter(|next_positions| !next_positions.is_empty())
+    }
+}
+
+fn run_instruction2(mut map: Grid<u8>, direction: &Direction) -> Grid<u8> {
+    let robot = map.find(b'@').unwrap();
+    if [Direction::East, Direction::West].contains(direction) {
+        let iterations = successors(Some(robot), |&position| go(&position, direction, &map))
+            .take_while(|&position| map[position] != b'#')
+            .position(|position| map[position] == b'.')
+            .map(|n| n + 1);
+
+        if let Some(n) = iterations {
+            let path = successors(Some(robot), |pos| go(pos, direction, &map))
+                .take(n)
+                .collect::<Vec<_>>();
+            let mut value = b'.';
+            for pos in path {
+                std::mem::swap(&mut value, &mut map[pos]);
+            }
+        }
+    } else {
+        let positions = Some(HashMap::from([(robot, (b'@', b'.'))]));
+        let mapping = successors(positions, move_ns(&map, direction)).collect::<Vec<_>>();
+        if mapping.last().unwrap().iter().all(|(_, (b, _))| *b == b'.') {
+            mapping
+                .into_iter()
+                .flatten()
+                .for_each(|(pos, (_, new))| map[pos] = new);
+        }
+    }
+
+    map
+}
+
+fn warehouse(map: &Grid<u8>) -> Render {
+    Render::new(map, |b| match b {
+        b'O' | b'[' | b']' => Cell::new(*b as char, Colour::YELLOW),
+        b'@' => Cell::new('@', Colour::RED),
+        b => render::map_cell(b),
+    })
+}
+
+pub struct Day15;
+
+fn gps_sum(map: &Grid<u8>, box_edge: u8) -> i64 {
+    map.iter()
+        .filter(|(_, &value)| value == box_edge)
+        .map(|(position, _)| 100 * position.y + position.x)
+        .sum::<i64>()
+}
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+
+    type Parsed<'a> = (Grid<u8>, Grid<u8>, Vec<Direction>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<(Grid<u8>, Grid<u8>, Vec<Direction>), ParseError> {
+        let (raw_map, instructions) = parse(input)?;
+        let map1 = Grid::parse_block(input, raw_map)?;
+        parse::walled(input, raw_map, '#')?;
+        let map2 = Grid::parse(&parse2(raw_map))?;
+        Ok((map1, map2, instructions))
+    }
+
+    fn part1(&self, (map1, _, instructions): &(Grid<u8>, Grid<u8>, Vec<Direction>)) -> i64 {
+        let map = instructions.iter().fold(map1.clone(), run_instruction);
+        gps_sum(&map, b'O')
+    }
+
+    fn part2(&self, (_, map2, instructions): &(Grid<u8>, Grid<u8>, Vec<Direction>)) -> i64 {
+        let map = instructions.iter().fold(map2.clone(), run_instruction2);
+        gps_sum(&map, b'[')
+    }
+
+    /// The wide warehouse after the robot has made all its moves.
+    fn render(
+        &self,
+        (_, map2, instructions): &(Grid<u8>, Grid<u8>, Vec<Direction>),
+    ) -> Option<Render> {
+        let map = instructions.iter().fold(map2.clone(), run_instruction2);
+        Some(warehouse(&map))
+    }
+
+    /// The wide warehouse before and after every move.
+    fn animate(
+        &self,
+        (_, map2, instructions): &(Grid<u8>, Grid<u8>, Vec<Direction>),
+        recorder: &mut Recorder,
+    ) {
+        let mut map = map2.clone();
+        recorder.record(|| warehouse(&map));
+        for direction in instructions {
+            map = run_instruction2(map, direction);
+            recorder.record(|| warehouse(&map));
+        }
+    }
+
+    /// A `size` by `size` walled warehouse with boxes on one in five cells,
+    /// a few walls inside and `size` times 20 moves in lines of 70.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let side = size.max(3) as i64;
+        let mut map = Grid::filled(side, side, b'#');
+        for position in map.positions() {
+            let inside = (1..side - 1).contains(&position.x) && (1..side - 1).contains(&position.y);
+            if inside {
+                map[position] = match rng.index(20) {
+                    0..=3 => b'O',
+                    4 => b'#',
+                    _ => b'.',
+                };
+            }
+        }
+        map[Position::new(rng.range(1..=side - 2), rng.range(1..=side - 2))] = b'@';
+        let moves = (0..side * 20)
+            .map(|index| {
+                let direction = *rng.choose(b"^>v<") as char;
+                if index % 70 == 69 {
+                    format!("{}\n", direction)
+                } else {
+                    direction.to_string()
+                }
+            })
+            .collect::<String>();
+        Some(Generated::new(format!("{}\n{}\n", map, moves.trim_end())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day15;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day15,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day15, &Part::BOTH, &[3, 20]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day15,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            300,
+        );
+    }
+}
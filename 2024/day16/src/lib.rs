@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+
+use aoc::{
+    generate::{self, Generated, Rng},
+    parse,
+    render::{Colour, Layer, Render},
+    search::{self, ShortestPaths},
+    Direction, Grid, ParseError, Position, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Vertex {
+    pos: Position,
+    dir: Direction,
+}
+
+impl Vertex {
+    fn forward(&self) -> Vertex {
+        Vertex {
+            pos: self.pos + self.dir,
+            dir: self.dir,
+        }
+    }
+
+    fn left(&self) -> Vertex {
+        Vertex {
+            pos: self.pos,
+            dir: self.dir.turn_left(),
+        }
+    }
+
+    fn right(&self) -> Vertex {
+        Vertex {
+            pos: self.pos,
+            dir: self.dir.turn_right(),
+        }
+    }
+
+    fn neighbors<'a>(&self, puzzle: &'a Grid<u8>) -> impl Iterator<Item = (Vertex, u64)> + 'a {
+        [
+            (self.forward(), 1),
+            (self.left(), 1000),
+            (self.right(), 1000),
+        ]
+        .into_iter()
+        .filter(|(v, _)| puzzle[v.pos] != b'#')
+    }
+}
+
+fn dijkstra(puzzle: &Grid<u8>) -> ShortestPaths<Vertex, u64> {
+    let start_vertex = Vertex {
+        pos: puzzle.find(b'S').unwrap(),
+        dir: Direction::East,
+    };
+    search::dijkstra([start_vertex], |vertex| vertex.neighbors(puzzle))
+}
+
+fn end_vertices(puzzle: &Grid<u8>) -> impl Iterator<Item = Vertex> {
+    let pos = puzzle.find(b'E').unwrap();
+    Direction::CARDINAL
+        .into_iter()
+        .map(move |dir| Vertex { pos, dir })
+}
+
+const NO_PATH: &str = "the reindeer cannot reach `E`";
+
+fn lowest_score(puzzle: &Grid<u8>, shortest: &ShortestPaths<Vertex, u64>) -> Option<u64> {
+    end_vertices(puzzle)
+        .filter_map(|vertex| shortest.cost(&vertex))
+        .min()
+}
+
+fn best_tiles(puzzle: &Grid<u8>, shortest: &ShortestPaths<Vertex, u64>) -> HashSet<Position> {
+    let Some(part1) = lowest_score(puzzle, shortest) else {
+        return HashSet::new();
+    };
+    let end_vertices = end_vertices(puzzle).filter(|vertex| shortest.cost(vertex) == Some(part1));
+    shortest
+        .nodes_on_paths_to(end_vertices)
+        .into_iter()
+        .map(|vertex| vertex.pos)
+        .collect()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+
+    type Parsed<'a> = Grid<u8>;
+    type Part1 = Result<u64, &'static str>;
+    type Part2 = Result<usize, &'static str>;
+
+    fn parse(&self, input: &str) -> Result<Grid<u8>, ParseError> {
+        parse::charset(input, "#.SE")?;
+        let puzzle = Grid::parse(input)?;
+        parse::walled(input, input, '#')?;
+        parse::unique(input, input, 'S')?;
+        parse::unique(input, input, 'E')?;
+        Ok(puzzle)
+    }
+
+    fn part1(&self, puzzle: &Grid<u8>) -> Result<u64, &'static str> {
+        lowest_score(puzzle, &dijkstra(puzzle)).ok_or(NO_PATH)
+    }
+
+    fn part2(&self, puzzle: &Grid<u8>) -> Result<usize, &'static str> {
+        match best_tiles(puzzle, &dijkstra(puzzle)).len() {
+            0 => Err(NO_PATH),
+            tiles => Ok(tiles),
+        }
+    }
+
+    /// Every tile the search reached, with the tiles on a best path on top.
+    fn render(&self, puzzle: &Grid<u8>) -> Option<Render> {
+        let shortest = dijkstra(puzzle);
+        let visited = shortest.costs().keys().map(|vertex| vertex.pos);
+        Some(
+            Render::map(puzzle)
+                .layer(Layer::new(visited, Colour::BLUE))
+                .layer(Layer::new(best_tiles(puzzle, &shortest), Colour::GREEN).glyph('O')),
+        )
+    }
+
+    /// A `size` by `size` maze, rounded up to an odd size, with one in ten
+    /// of its inner walls knocked through so that there are loops and ties
+    /// between the best paths.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut maze = generate::maze(rng, size.max(5), size.max(5));
+        let (width, height) = (maze.width(), maze.height());
+        for position in maze.positions() {
+            let inside =
+                (1..width - 1).contains(&position.x) && (1..height - 1).contains(&position.y);
+            if inside && (position.x + position.y) % 2 == 1 && rng.chance(0.1) {
+                maze[position] = b'.';
+            }
+        }
+        maze[Position::new(1, height - 2)] = b'S';
+        maze[Position::new(width - 2, 1)] = b'E';
+        Some(Generated::new(maze.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day16;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day16,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day16, &Part::BOTH, &[5, 21]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day16,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
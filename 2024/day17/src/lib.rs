@@ -0,0 +1,263 @@
+use std::str::FromStr;
+
+use aoc::{
+    combinators::{line_break, separated, take_while, unsigned, Parser},
+    generate::{Generated, Rng},
+    ParseError, Solution,
+};
+
+#[derive(Clone, Copy)]
+pub struct Registers {
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+impl Registers {
+    fn new(a: u64) -> Registers {
+        Registers { a, b: 0, c: 0 }
+    }
+}
+
+impl Registers {
+    fn combo(&self, operand: u64) -> u64 {
+        match operand {
+            0..=3 => operand,
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            _ => unreachable!("parsing rejects combo operand 7"),
+        }
+    }
+
+    /// Register A divided by 2 to the power of the combo operand.
+    fn divide(&self, operand: u64) -> u64 {
+        u32::try_from(self.combo(operand))
+            .ok()
+            .and_then(|shift| self.a.checked_shr(shift))
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Instruction, String> {
+        match s {
+            "0" => Ok(Instruction::Adv),
+            "1" => Ok(Instruction::Bxl),
+            "2" => Ok(Instruction::Bst),
+            "3" => Ok(Instruction::Jnz),
+            "4" => Ok(Instruction::Bxc),
+            "5" => Ok(Instruction::Out),
+            "6" => Ok(Instruction::Bdv),
+            "7" => Ok(Instruction::Cdv),
+            _ => Err(s.to_string()),
+        }
+    }
+}
+
+type Operand = u64;
+
+type Program = Vec<(Instruction, Operand)>;
+
+fn parse(puzzle: &str) -> Result<(Registers, Program), ParseError> {
+    let register = |name| {
+        "Register "
+            .ignore_then(name)
+            .ignore_then(": ")
+            .ignore_then(unsigned())
+            .then_ignore(line_break())
+    };
+    let registers = register("A")
+        .then(register("B"))
+        .then(register("C"))
+        .map(|((a, b), c)| Registers { a, b, c });
+    let instruction = take_while(|c| c.is_ascii_digit())
+        .try_map(|s: &str| s.parse::<Instruction>().map_err(|_| "an instruction 0-7"));
+    let operand = unsigned::<Operand>().try_map(|operand| match operand {
+        0..8 => Ok(operand),
+        _ => Err("an operand 0-7"),
+    });
+    let step = instruction
+        .then_ignore(",")
+        .then(operand)
+        .try_map(|(instruction, operand)| match (instruction, operand) {
+            (Instruction::Jnz, _) if operand % 2 == 1 => Err("an even jump target"),
+            (Instruction::Adv, 7)
+            | (Instruction::Bst, 7)
+            | (Instruction::Out, 7)
+            | (Instruction::Bdv, 7)
+            | (Instruction::Cdv, 7) => Err("a combo operand 0-6"),
+            _ => Ok((instruction, operand)),
+        });
+    let program = "Program: ".ignore_then(separated(step, ","));
+    registers
+        .then_ignore(line_break())
+        .then(program)
+        .parse_all(puzzle)
+}
+
+/// Far more steps than the puzzle's programs take, standing in for telling
+/// whether a program halts.
+const MAX_STEPS: usize = 100_000;
+
+const NO_HALT: &str = "the program does not halt";
+
+fn run(mut registers: Registers, program: &Program) -> Result<Vec<u64>, &'static str> {
+    let mut output = Vec::new();
+    let mut instruction_pointer = 0;
+    for _ in 0..MAX_STEPS {
+        if instruction_pointer >= program.len() {
+            return Ok(output);
+        }
+        let (instruction, operand) = program[instruction_pointer];
+        match instruction {
+            Instruction::Adv => registers.a = registers.divide(operand),
+            Instruction::Bxl => registers.b ^= operand,
+            Instruction::Bst => registers.b = registers.combo(operand) % 8,
+            Instruction::Jnz => {
+                if registers.a != 0 {
+                    // Jump targets count numbers, two to an instruction.
+                    instruction_pointer = (operand / 2) as usize;
+                    continue;
+                }
+            }
+            Instruction::Bxc => registers.b ^= registers.c,
+            Instruction::Out => output.push(registers.combo(operand) % 8),
+            Instruction::Bdv => registers.b = registers.divide(operand),
+            Instruction::Cdv => registers.c = registers.divide(operand),
+        }
+        instruction_pointer += 1;
+    }
+    Err(NO_HALT)
+}
+
+fn get_output_end(program: &Program, n: usize) -> Vec<u64> {
+    let raw = program
+        .iter()
+        .flat_map(|&(instruction, operand)| [instruction as u64, operand])
+        .collect::<Vec<_>>();
+    raw[raw.len() - n..].to_vec()
+}
+
+/// The lowest value of register A that makes `program` output itself.
+fn quine(program: &Program) -> Option<u64> {
+    // Program has the form
+    //
+    // while a != 0:
+    //     # bitwise stuff
+    //     a = a >> 3
+    //
+    // so we can compute the input value of a by starting with
+    // an a that only has the lowest 3 bits set, then shifting
+    // those 3 to the left and figuring out the next value of
+    // the lowest 3 bits.
+    let mut stack = vec![0u64];
+    for j in 1..=program.len() * 2 {
+        let expected = get_output_end(program, j);
+        let mut new_stack = Vec::new();
+        for a in stack.iter() {
+            for lower in 0..8 {
+                // Past 64 bits there is no register A to find.
+                let Some(current_a) = a.checked_mul(8).map(|a| a + lower) else {
+                    continue;
+                };
+                if run(Registers::new(current_a), program).is_ok_and(|output| output == expected) {
+                    new_stack.push(current_a);
+                }
+            }
+        }
+        stack = new_stack
+    }
+
+    stack.into_iter().min()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 17;
+
+    type Parsed<'a> = (Registers, Program);
+    type Part1 = Result<Vec<u64>, &'static str>;
+    type Part2 = Result<u64, &'static str>;
+
+    fn parse(&self, input: &str) -> Result<(Registers, Program), ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, (registers, program): &(Registers, Program)) -> Result<Vec<u64>, &'static str> {
+        run(*registers, program)
+    }
+
+    fn part2(&self, (_, program): &(Registers, Program)) -> Result<u64, &'static str> {
+        quine(program).ok_or("no value of register A makes the program output itself")
+    }
+
+    /// A program of the usual shape, a loop of `bst`, two `bxl`s, `cdv`,
+    /// `bxc`, `out` and `adv 3`, with random constants that let it output
+    /// itself, and a random register A. `size` is not used.
+    fn generate(&self, rng: &mut Rng, _size: usize) -> Option<Generated> {
+        loop {
+            let (k1, k2, x) = (rng.index(8), rng.index(8), rng.index(8));
+            let mut program = if rng.chance(0.5) {
+                vec![2, 4, 1, k1, 7, 5, 1, k2, 4, x]
+            } else {
+                vec![2, 4, 1, k1, 7, 5, 4, x, 1, k2]
+            };
+            program.extend([5, 5, 0, 3, 3, 0]);
+            let program = program.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+            let input = format!(
+                "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                rng.below(1 << 46),
+                program.join(",")
+            );
+            if quine(&parse(&input).unwrap().1).is_some() {
+                return Some(Generated::new(input));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day17;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day17,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day17, &Part::BOTH, &[0]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day17,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
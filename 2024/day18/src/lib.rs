@@ -0,0 +1,182 @@
+use std::collections::HashSet;
+
+use aoc::{
+    generate::{Generated, Rng},
+    parse,
+    render::{Cell, Colour, Layer, Render},
+    search::{self, ShortestPaths},
+    Grid, Param, ParseError, Position, Solution,
+};
+
+const START: Position = Position { x: 0, y: 0 };
+
+fn parse(puzzle: &str) -> Result<Vec<Position>, ParseError> {
+    parse::lines(puzzle)
+        .map(|line| {
+            let (x, y) = parse::split_once(puzzle, line, ",")?;
+            Ok(Position {
+                x: parse::number(puzzle, x)?,
+                y: parse::number(puzzle, y)?,
+            })
+        })
+        .collect()
+}
+
+fn new_blocked(fallen: &[Position], limit: usize) -> HashSet<Position> {
+    fallen.iter().take(limit).cloned().collect()
+}
+
+fn search(parsed: &[Position], size: i64, limit: usize) -> ShortestPaths<Position, usize> {
+    let blocked = new_blocked(parsed, limit);
+    let grid = Grid::new(size, size);
+
+    search::bfs([START], |&pos| {
+        grid.neighbours(pos)
+            .map(|(_, neighbor)| neighbor)
+            .filter(|neighbor| !blocked.contains(neighbor))
+            .collect::<Vec<_>>()
+    })
+}
+
+fn find_steps(parsed: &[Position], size: i64, limit: usize) -> Option<usize> {
+    search(parsed, size, limit).cost(&Position::new(size - 1, size - 1))
+}
+
+const MAX_SIDE: i64 = 1000;
+
+pub struct Day18 {
+    pub size: i64,
+    pub bytes: usize,
+}
+
+impl Default for Day18 {
+    fn default() -> Day18 {
+        Day18 {
+            size: 71,
+            bytes: 1024,
+        }
+    }
+}
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+
+    type Parsed<'a> = Vec<Position>;
+    type Part1 = Result<usize, String>;
+    type Part2 = Result<Position, &'static str>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Position>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, parsed: &Vec<Position>) -> Result<usize, String> {
+        find_steps(parsed, self.size, self.bytes)
+            .ok_or_else(|| format!("the exit is cut off after {} bytes", self.bytes))
+    }
+
+    fn part2(&self, parsed: &Vec<Position>) -> Result<Position, &'static str> {
+        let index = (0..=parsed.len())
+            .collect::<Vec<_>>()
+            .partition_point(|&limit| find_steps(parsed, self.size, limit).is_some());
+        match index {
+            0 => Err("the exit cannot be reached at all"),
+            index => parsed
+                .get(index - 1)
+                .copied()
+                .ok_or("the exit is never cut off"),
+        }
+    }
+
+    fn params_mut(&mut self) -> Vec<(&'static str, &mut dyn Param)> {
+        vec![("size", &mut self.size), ("bytes", &mut self.bytes)]
+    }
+
+    fn check_params(&self) -> Result<(), String> {
+        match (1..=MAX_SIDE).contains(&self.size) {
+            true => Ok(()),
+            false => Err(format!(
+                "the memory space must be 1 to {} bytes on each side",
+                MAX_SIDE
+            )),
+        }
+    }
+
+    /// The memory space after the first `bytes` have fallen, with a shortest
+    /// path to the exit if there is one.
+    fn render(&self, parsed: &Vec<Position>) -> Option<Render> {
+        let exit = Position::new(self.size - 1, self.size - 1);
+        let path = search(parsed, self.size, self.bytes)
+            .path_to(&exit)
+            .unwrap_or_default();
+        let space = Grid::new(self.size, self.size);
+        Some(
+            Render::new(&space, |_| Cell::new('.', Colour::DARK_GREY))
+                .layer(Layer::new(new_blocked(parsed, self.bytes), Colour::RED).glyph('#'))
+                .layer(Layer::new(path, Colour::GREEN).glyph('O')),
+        )
+    }
+
+    /// Bytes falling on the free cells of the memory space in random order
+    /// until the exit is cut off, and then up to `size` bytes in all. The
+    /// first `bytes` of them leave a way out.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let exit = Position::new(self.size - 1, self.size - 1);
+        let mut cells = Grid::new(self.size, self.size)
+            .positions()
+            .filter(|&cell| cell != START && cell != exit)
+            .collect::<Vec<_>>();
+        for _ in 0..100 {
+            rng.shuffle(&mut cells);
+            let blocked = (0..=cells.len())
+                .collect::<Vec<_>>()
+                .partition_point(|&limit| find_steps(&cells, self.size, limit).is_some());
+            if blocked > self.bytes {
+                let input = cells[..size.clamp(blocked, cells.len())]
+                    .iter()
+                    .map(|cell| format!("{},{}\n", cell.x, cell.y))
+                    .collect::<String>();
+                return Some(Generated::new(input));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Part, Solver};
+
+    use crate::Day18;
+
+    #[test]
+    fn test_params() {
+        let mut day = Day18::default();
+        assert!(day.set_param("size", "0").is_err());
+        assert!(day.set_param("size", "1001").is_err());
+        assert_eq!(day.params()[0], ("size", "71".to_string()));
+        assert!(day.set_param("size", "7").is_ok());
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day18::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day18::default(), &Part::BOTH, &[0, 3000]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day18::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
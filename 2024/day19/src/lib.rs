@@ -0,0 +1,229 @@
+use std::{array::from_fn, cmp::min, collections::HashSet};
+
+use aoc::{
+    generate::{Generated, Rng},
+    parse, Cache, ParseError, Solution,
+};
+
+mod post;
+
+pub use post::Day19Post;
+
+const MAX_AVAILABLE_LEN: usize = 8;
+const MAX_AVAILABLE_ERROR: &str = "a towel of 1 to 8 stripes";
+
+fn parse(puzzle: &str) -> Result<(HashSet<&str>, Vec<&str>), ParseError> {
+    parse::charset(puzzle, "wubrg, ")?;
+    let mut blocks = parse::blocks(puzzle);
+    let available = blocks
+        .next()
+        .unwrap_or_default()
+        .split(", ")
+        .map(|towel| match towel.len() {
+            1..=MAX_AVAILABLE_LEN => Ok(towel),
+            _ => Err(ParseError::new(puzzle, towel, MAX_AVAILABLE_ERROR)),
+        })
+        .collect::<Result<_, _>>()?;
+    let targets = blocks
+        .next()
+        .ok_or_else(|| {
+            ParseError::new(puzzle, &puzzle[puzzle.len()..], "a blank line and designs")
+        })?
+        .lines()
+        .collect();
+    Ok((available, targets))
+}
+
+fn is_buildable_small(available: &[HashSet<&str>; MAX_AVAILABLE_LEN], target: &str) -> bool {
+    target.is_empty()
+        || (1..=min(target.len(), MAX_AVAILABLE_LEN))
+            .filter(|&len| available[len - 1].contains(&target[..len]))
+            .any(|len| is_buildable_small(available, &target[len..]))
+}
+
+fn is_buildable(available: &[HashSet<&str>; MAX_AVAILABLE_LEN], target: &str) -> bool {
+    if target.len() < 2 * MAX_AVAILABLE_LEN {
+        is_buildable_small(available, target)
+    } else {
+        (1..=MAX_AVAILABLE_LEN)
+            .flat_map(|len| {
+                let midsection_start = target.len() / 2 - len + 1;
+                (midsection_start..=target.len() / 2).map(move |index| (index, len))
+            })
+            .filter(|&(index, len)| available[len - 1].contains(&target[index..index + len]))
+            .any(|(index, len)| {
+                is_buildable(available, &target[..index])
+                    && is_buildable(available, &target[index + len..])
+            })
+    }
+}
+
+// Long designs of short towels have more arrangements than fit in 64 bits.
+const OVERFLOW: &str = "the number of arrangements overflows";
+
+fn count_small(available: &[HashSet<&str>; MAX_AVAILABLE_LEN], target: &str) -> Option<u64> {
+    if target.is_empty() {
+        Some(1)
+    } else {
+        (1..=min(target.len(), MAX_AVAILABLE_LEN))
+            .filter(|&len| available[len - 1].contains(&target[..len]))
+            .try_fold(0u64, |sum, j| {
+                sum.checked_add(count_small(available, &target[j..])?)
+            })
+    }
+}
+
+fn count(
+    available: &[HashSet<&str>; MAX_AVAILABLE_LEN],
+    target: &str,
+    count: &mut dyn FnMut(&str) -> Option<u64>,
+) -> Option<u64> {
+    if target.len() < 2 * MAX_AVAILABLE_LEN {
+        count_small(available, target)
+    } else {
+        (1..=MAX_AVAILABLE_LEN)
+            .flat_map(|len| {
+                let midsection_start = target.len() / 2 - len + 1;
+                (midsection_start..=target.len() / 2).map(move |index| (index, len))
+            })
+            .filter(|&(index, len)| available[len - 1].contains(&target[index..index + len]))
+            .try_fold(0u64, |sum, (index, len)| {
+                sum.checked_add(
+                    count(&target[..index])?.checked_mul(count(&target[index + len..])?)?,
+                )
+            })
+    }
+}
+
+type Available<'a> = [HashSet<&'a str>; MAX_AVAILABLE_LEN];
+
+fn random_onsen(rng: &mut Rng, size: usize) -> Generated {
+    let colours = b"wubrg";
+    let stripes = |rng: &mut Rng, len| {
+        (0..len)
+            .map(|_| *rng.choose(colours) as char)
+            .collect::<String>()
+    };
+    let missing = rng.index(colours.len());
+    let mut towels = (0..colours.len())
+        .filter(|&index| index != missing)
+        .map(|index| (colours[index] as char).to_string())
+        .collect::<Vec<_>>();
+    for _ in 0..60 {
+        let len = rng.range(2..=MAX_AVAILABLE_LEN as i64) as usize;
+        towels.push(stripes(rng, len));
+    }
+    towels.sort();
+    towels.dedup();
+    let designs = (0..size.max(1))
+        .map(|_| {
+            let len = rng.range(20..=50) as usize;
+            if rng.chance(0.3) {
+                return stripes(rng, len);
+            }
+            let mut design = String::new();
+            while design.len() < len {
+                design += rng.choose(&towels).as_str();
+            }
+            design
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut towels);
+    Generated::new(format!("{}\n\n{}\n", towels.join(", "), designs.join("\n")))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 19;
+
+    type Parsed<'a> = (Available<'a>, Vec<&'a str>);
+    type Part1 = usize;
+    type Part2 = Result<u64, &'static str>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<(Available<'a>, Vec<&'a str>), ParseError> {
+        let (available, targets) = parse(input)?;
+
+        let mut available_partitioned =
+            from_fn::<HashSet<_>, MAX_AVAILABLE_LEN, _>(|_| HashSet::new());
+        for a in available {
+            available_partitioned[a.len() - 1].insert(a);
+        }
+        Ok((available_partitioned, targets))
+    }
+
+    fn part1(&self, (available, targets): &(Available, Vec<&str>)) -> usize {
+        targets
+            .iter()
+            .filter(|target| is_buildable(available, target))
+            .count()
+    }
+
+    fn part2(&self, (available, targets): &(Available, Vec<&str>)) -> Result<u64, &'static str> {
+        let mut cache = Cache::new(
+            |target: &str, recurse: &mut dyn FnMut(&str) -> Option<u64>| {
+                count(available, target, recurse)
+            },
+        );
+        targets
+            .iter()
+            .try_fold(0u64, |sum, target| {
+                sum.checked_add(cache.get_or_compute(target)?)
+            })
+            .ok_or(OVERFLOW)
+    }
+
+    /// `size` designs, most of them made of the towels on offer. All towels
+    /// of a single stripe but one are on offer, like in the real input.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(random_onsen(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Part, Solver};
+
+    use crate::{Day19, Day19Post};
+
+    #[test]
+    fn test_examples() {
+        let examples = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+        aoc::examples::assert_examples(&mut Day19, examples);
+        aoc::examples::assert_examples(&mut Day19Post, examples);
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day19, &Part::BOTH, &[1, 50]);
+        for seed in 0..3 {
+            let input = Day19.generate(seed, 50).unwrap().input;
+            assert_eq!(
+                Day19.solve(&input, &Part::BOTH),
+                Day19Post.solve(&input, &Part::BOTH),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_at_the_end() {
+        // The towel at the middle of this design ends it, so what comes
+        // before it has to be the first half rather than the whole design.
+        let input = "b, wwwwwwww\n\nggggggggwwwwwwww\n";
+        for day in [&Day19 as &dyn Solver, &Day19Post] {
+            let answers = day.solve(input, &Part::BOTH).unwrap();
+            assert_eq!(answers[0].1, Ok(0usize.into()));
+            assert_eq!(answers[1].1, Ok(0u64.into()));
+        }
+    }
+
+    #[test]
+    fn test_fuzz() {
+        let examples = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+        aoc::fuzz::assert_total(&mut Day19, examples, 1000);
+        aoc::fuzz::assert_total(&mut Day19Post, examples, 1000);
+    }
+}
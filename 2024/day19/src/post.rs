@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use aoc::{
+    generate::{Generated, Rng},
+    parse, Cache, ParseError, Solution,
+};
+
+fn parse(puzzle: &str) -> Result<(HashSet<&str>, Vec<&str>), ParseError> {
+    parse::charset(puzzle, "wubrg, ")?;
+    let mut blocks = parse::blocks(puzzle);
+    let available = blocks
+        .next()
+        .unwrap_or_default()
+        .split(", ")
+        .map(|towel| match towel.len() {
+            0 => Err(ParseError::new(puzzle, towel, "a towel")),
+            _ => Ok(towel),
+        })
+        .collect::<Result<_, _>>()?;
+    let targets = blocks
+        .next()
+        .ok_or_else(|| {
+            ParseError::new(puzzle, &puzzle[puzzle.len()..], "a blank line and designs")
+        })?
+        .lines()
+        .collect();
+    Ok((available, targets))
+}
+
+fn is_buildable(
+    available: &HashSet<&str>,
+    target: &str,
+    is_buildable: &mut dyn FnMut(&str) -> bool,
+) -> bool {
+    target.is_empty()
+        || (1..=target.len())
+            .filter(|&j| available.contains(&target[..j]))
+            .any(|j| is_buildable(&target[j..]))
+}
+
+fn count(
+    available: &HashSet<&str>,
+    target: &str,
+    count: &mut dyn FnMut(&str) -> Option<u64>,
+) -> Option<u64> {
+    if target.is_empty() {
+        Some(1)
+    } else {
+        (1..=target.len())
+            .filter(|&j| available.contains(&target[..j]))
+            .try_fold(0u64, |sum, j| sum.checked_add(count(&target[j..])?))
+    }
+}
+
+pub struct Day19Post;
+
+impl Solution for Day19Post {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 19;
+    const VARIANT: &'static str = "prefix";
+
+    type Parsed<'a> = (HashSet<&'a str>, Vec<&'a str>);
+    type Part1 = usize;
+    type Part2 = Result<u64, &'static str>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<(HashSet<&'a str>, Vec<&'a str>), ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, (available, targets): &(HashSet<&str>, Vec<&str>)) -> usize {
+        let mut cache = Cache::new(|target: &str, recurse: &mut dyn FnMut(&str) -> bool| {
+            is_buildable(available, target, recurse)
+        });
+        targets
+            .iter()
+            .filter(|target| cache.get_or_compute(target))
+            .count()
+    }
+
+    fn part2(
+        &self,
+        (available, targets): &(HashSet<&str>, Vec<&str>),
+    ) -> Result<u64, &'static str> {
+        let mut cache = Cache::new(
+            |target: &str, recurse: &mut dyn FnMut(&str) -> Option<u64>| {
+                count(available, target, recurse)
+            },
+        );
+        targets
+            .iter()
+            .try_fold(0u64, |sum, target| {
+                sum.checked_add(cache.get_or_compute(target)?)
+            })
+            .ok_or(crate::OVERFLOW)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(crate::random_onsen(rng, size))
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use aoc::{
+    generate::{self, Generated, Rng},
+    parse, search, tc_dist, Grid, Neighbourhood, Param, ParseError, Position, Solution,
+};
+
+fn steps_from(puzzle: &Grid<u8>, start: Position) -> HashMap<Position, usize> {
+    search::bfs([start], |&pos| {
+        puzzle
+            .neighbours(pos)
+            .map(|(_, neighbor)| neighbor)
+            .filter(|&neighbor| puzzle[neighbor] != b'#')
+            .collect::<Vec<_>>()
+    })
+    .into_costs()
+}
+
+const NO_PATH: &str = "there is no track from `S` to `E`";
+
+fn part1(
+    puzzle: &Grid<u8>,
+    start: Position,
+    end: Position,
+    save_at_least: usize,
+) -> Result<usize, &'static str> {
+    let steps_from_start = steps_from(puzzle, start);
+    let steps_from_end = steps_from(puzzle, end);
+    let part1_no_cheat = *steps_from_start.get(&end).ok_or(NO_PATH)?;
+    Ok(puzzle
+        .positions()
+        .filter(|&pos| puzzle[pos] == b'#')
+        .flat_map(|wall| {
+            puzzle
+                .neighbours(wall)
+                .flat_map(move |(_, start2)| {
+                    puzzle
+                        .neighbours(wall)
+                        .map(move |(_, end1)| (end1, wall, start2))
+                })
+                .filter(|(end1, _, start2)| puzzle[*start2] != b'#' && puzzle[*end1] != b'#')
+        })
+        // Cheats between track and cut off pockets of it are no shortcut.
+        .filter_map(|(end1, _, start2)| {
+            Some(2 + steps_from_start.get(&end1)? + steps_from_end.get(&start2)?)
+        })
+        .filter(|&total_steps| {
+            part1_no_cheat
+                .checked_sub(save_at_least)
+                .is_some_and(|limit| total_steps <= limit)
+        })
+        .count())
+}
+
+fn part2(
+    puzzle: &Grid<u8>,
+    start: Position,
+    end: Position,
+    save_at_least: usize,
+    cheat_length: i64,
+) -> Result<usize, &'static str> {
+    let steps_from_start = steps_from(puzzle, start);
+    let steps_from_end = steps_from(puzzle, end);
+    let part1_no_cheat = *steps_from_start.get(&end).ok_or(NO_PATH)?;
+    let cheats = Neighbourhood::manhattan(cheat_length);
+    Ok(puzzle
+        .positions()
+        .filter(|&end1| puzzle[end1] != b'#')
+        .flat_map(|end1| {
+            puzzle
+                .neighbourhood(end1, &cheats)
+                .filter(|&start2| puzzle[start2] != b'#')
+                .map(move |start2| (end1, start2, tc_dist(end1, start2) as usize))
+        })
+        .filter_map(|(end1, start2, skipped)| {
+            Some(skipped + steps_from_start.get(&end1)? + steps_from_end.get(&start2)?)
+        })
+        .filter(|&total_steps| {
+            part1_no_cheat
+                .checked_sub(save_at_least)
+                .is_some_and(|limit| total_steps <= limit)
+        })
+        .count())
+}
+
+const MAX_CHEAT: i64 = 1000;
+
+pub struct Day20 {
+    pub save_at_least: usize,
+    pub cheat_length: i64,
+}
+
+impl Default for Day20 {
+    fn default() -> Day20 {
+        Day20 {
+            save_at_least: 100,
+            cheat_length: 20,
+        }
+    }
+}
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 20;
+
+    type Parsed<'a> = (Grid<u8>, Position, Position);
+    type Part1 = Result<usize, &'static str>;
+    type Part2 = Result<usize, &'static str>;
+
+    fn parse(&self, input: &str) -> Result<(Grid<u8>, Position, Position), ParseError> {
+        parse::charset(input, "#.SE")?;
+        let puzzle = Grid::parse(input)?;
+        parse::unique(input, input, 'S')?;
+        parse::unique(input, input, 'E')?;
+        let (start, end) = (puzzle.find(b'S').unwrap(), puzzle.find(b'E').unwrap());
+        Ok((puzzle, start, end))
+    }
+
+    fn part1(
+        &self,
+        (puzzle, start, end): &(Grid<u8>, Position, Position),
+    ) -> Result<usize, &'static str> {
+        part1(puzzle, *start, *end, self.save_at_least)
+    }
+
+    fn part2(
+        &self,
+        (puzzle, start, end): &(Grid<u8>, Position, Position),
+    ) -> Result<usize, &'static str> {
+        part2(puzzle, *start, *end, self.save_at_least, self.cheat_length)
+    }
+
+    fn params_mut(&mut self) -> Vec<(&'static str, &mut dyn Param)> {
+        vec![
+            ("save_at_least", &mut self.save_at_least),
+            ("cheat_length", &mut self.cheat_length),
+        ]
+    }
+
+    fn check_params(&self) -> Result<(), String> {
+        match (0..=MAX_CHEAT).contains(&self.cheat_length) {
+            true => Ok(()),
+            false => Err(format!("a cheat must last 0 to {} picoseconds", MAX_CHEAT)),
+        }
+    }
+
+    /// A `size` by `size` racetrack, rounded up to an odd size, that winds
+    /// through walls as the only path from one corner to the other in a
+    /// random maze.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let maze = generate::maze(rng, size.max(5), size.max(5));
+        let (start, end) = (
+            Position::new(1, 1),
+            Position::new(maze.width() - 2, maze.height() - 2),
+        );
+        let mut puzzle = Grid::filled(maze.width(), maze.height(), b'#');
+        let path = search::bfs([start], |&pos| {
+            maze.neighbours(pos)
+                .map(|(_, neighbor)| neighbor)
+                .filter(|&neighbor| maze[neighbor] != b'#')
+                .collect::<Vec<_>>()
+        })
+        .path_to(&end)?;
+        for position in path {
+            puzzle[position] = b'.';
+        }
+        puzzle[start] = b'S';
+        puzzle[end] = b'E';
+        Some(Generated::new(puzzle.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Part, Solver};
+
+    use crate::Day20;
+
+    #[test]
+    fn test_params() {
+        let mut day = Day20::default();
+        assert!(day.set_param("cheat_length", "-1").is_err());
+        assert!(day.set_param("cheat_length", "1001").is_err());
+        assert!(day
+            .set_param("save_at_least", &usize::MAX.to_string())
+            .is_ok());
+        let input = "###\n#S#\n#.#\n#E#\n###\n";
+        assert_eq!(day.solve_part(input, Part::Two).unwrap(), 0usize.into());
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day20::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day20::default(), &Part::BOTH, &[5, 41]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day20::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
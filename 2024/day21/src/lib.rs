@@ -0,0 +1,225 @@
+use std::{collections::HashMap, iter::once};
+
+use aoc::{
+    generate::{Generated, Rng},
+    parse, search, Direction, Grid, Param, ParseError, Position, Solution,
+};
+
+const ANUM: Position = Position { x: 2, y: 3 };
+const FORBIDDENNUM: Position = Position { x: 0, y: 3 };
+
+const UP: Position = Position { x: 1, y: 0 };
+const A: Position = Position { x: 2, y: 0 };
+const LEFT: Position = Position { x: 0, y: 1 };
+const DOWN: Position = Position { x: 1, y: 1 };
+const RIGHT: Position = Position { x: 2, y: 1 };
+const BUTTONS: [Position; 5] = [UP, A, LEFT, DOWN, RIGHT];
+const FORBIDDEN: Position = Position { x: 0, y: 0 };
+
+fn get_pos(b: u8) -> Position {
+    match b {
+        b'7' => Position { x: 0, y: 0 },
+        b'8' => Position { x: 1, y: 0 },
+        b'9' => Position { x: 2, y: 0 },
+        b'4' => Position { x: 0, y: 1 },
+        b'5' => Position { x: 1, y: 1 },
+        b'6' => Position { x: 2, y: 1 },
+        b'1' => Position { x: 0, y: 2 },
+        b'2' => Position { x: 1, y: 2 },
+        b'3' => Position { x: 2, y: 2 },
+        b'0' => Position { x: 1, y: 3 },
+        b'A' => Position { x: 2, y: 3 },
+        _ => unreachable!("parsing only lets digits and `A` through"),
+    }
+}
+
+fn pairs() -> impl Iterator<Item = (Position, Position)> {
+    BUTTONS
+        .iter()
+        .flat_map(|&b| BUTTONS.iter().map(move |&b2| (b, b2)))
+}
+
+type Codes = Vec<(Vec<Position>, usize)>;
+
+fn parse(puzzle: &str) -> Result<Codes, ParseError> {
+    parse::lines(puzzle)
+        .map(|s| {
+            let digits = s
+                .strip_suffix('A')
+                .filter(|digits| digits.len() == 3 && digits.bytes().all(|b| b.is_ascii_digit()));
+            let Some(digits) = digits else {
+                return Err(ParseError::new(puzzle, s, "a code of three digits and `A`"));
+            };
+            let buttons = once(ANUM).chain(s.bytes().map(get_pos)).collect();
+            Ok((buttons, parse::number(puzzle, digits)?))
+        })
+        .collect()
+}
+
+type ShortestPaths = HashMap<(Position, Position), usize>;
+
+fn key_for(from: Position, to: Position) -> Position {
+    match Direction::from_delta(to - from) {
+        Some(Direction::East) => RIGHT,
+        Some(Direction::North) => UP,
+        Some(Direction::West) => LEFT,
+        Some(Direction::South) => DOWN,
+        _ => unreachable!("keypad moves are orthogonal"),
+    }
+}
+
+fn all_paths(
+    button1: Position,
+    button2: Position,
+    forbidden: Position,
+) -> impl Iterator<Item = Vec<Position>> {
+    let grid = Grid::new(3, 4);
+    search::bfs([button1], |&pos| {
+        grid.neighbours(pos)
+            .map(|(_, next_pos)| next_pos)
+            .filter(|&next_pos| next_pos != forbidden)
+            .collect::<Vec<_>>()
+    })
+    .all_paths_to(&button2)
+    .into_iter()
+    .map(|path| {
+        once(A)
+            .chain(path.windows(2).map(|w| key_for(w[0], w[1])))
+            .chain(once(A))
+            .collect()
+    })
+}
+
+/// The lengths grow exponentially with the keypads, so every step is
+/// checked and `None` means an overflow.
+fn shortest_path_pairs(
+    button1: Position,
+    button2: Position,
+    shortest_paths: &ShortestPaths,
+    forbidden: Position,
+) -> Option<usize> {
+    all_paths(button1, button2, forbidden)
+        .filter_map(|p| {
+            p.windows(2).try_fold(0usize, |sum, w| {
+                sum.checked_add(shortest_paths[&(w[0], w[1])])
+            })
+        })
+        .min()
+}
+
+fn next_shortest_paths(shortest_paths: &ShortestPaths) -> Option<ShortestPaths> {
+    pairs()
+        .map(|(button1, button2)| {
+            let shortest_path = shortest_path_pairs(button1, button2, shortest_paths, FORBIDDEN)?;
+            Some(((button1, button2), shortest_path))
+        })
+        .collect()
+}
+
+fn shortest_path(code: &[Position], shortest_paths: &ShortestPaths) -> Option<usize> {
+    code.windows(2).try_fold(0usize, |sum, w| {
+        let pair = shortest_path_pairs(w[0], w[1], shortest_paths, FORBIDDENNUM)?;
+        sum.checked_add(pair)
+    })
+}
+
+fn complexity_sums(codes: &Codes, keypads: usize) -> Result<usize, &'static str> {
+    // Compile all the directional keypads into a single lookup for the shortest path between
+    // key pairs on the first directional keypad. Computed from the bottom up, dp style.
+    let first = HashMap::from_iter(pairs().map(|p| (p, 1)));
+    (0..keypads)
+        .try_fold(first, |acc, _| next_shortest_paths(&acc))
+        .and_then(|shortest_paths| {
+            codes.iter().try_fold(0usize, |sum, (code, number)| {
+                sum.checked_add(number.checked_mul(shortest_path(code, &shortest_paths)?)?)
+            })
+        })
+        .ok_or("the complexity overflows")
+}
+
+pub struct Day21 {
+    pub robots_part1: usize,
+    pub robots_part2: usize,
+}
+
+impl Default for Day21 {
+    fn default() -> Day21 {
+        Day21 {
+            robots_part1: 2,
+            robots_part2: 25,
+        }
+    }
+}
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 21;
+
+    type Parsed<'a> = Codes;
+    type Part1 = Result<usize, &'static str>;
+    type Part2 = Result<usize, &'static str>;
+
+    fn parse(&self, input: &str) -> Result<Codes, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, codes: &Codes) -> Result<usize, &'static str> {
+        complexity_sums(codes, self.robots_part1)
+    }
+
+    fn part2(&self, codes: &Codes) -> Result<usize, &'static str> {
+        complexity_sums(codes, self.robots_part2)
+    }
+
+    fn params_mut(&mut self) -> Vec<(&'static str, &mut dyn Param)> {
+        vec![
+            ("robots_part1", &mut self.robots_part1),
+            ("robots_part2", &mut self.robots_part2),
+        ]
+    }
+
+    /// `size` codes of three random digits.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let codes = (0..size.max(1))
+            .map(|_| format!("{:03}A\n", rng.below(1000)))
+            .collect::<String>();
+        Some(Generated::new(codes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Part, Solver};
+
+    use crate::Day21;
+
+    #[test]
+    fn test_overflow() {
+        let mut day = Day21::default();
+        day.set_param("robots_part2", "200").unwrap();
+        let error = day.solve_part("029A\n", Part::Two).unwrap_err();
+        assert!(error.to_string().ends_with("the complexity overflows"));
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day21::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day21::default(), &Part::BOTH, &[1, 5]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day21::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            200,
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use std::{collections::HashMap, iter::successors};
+
+use aoc::{
+    generate::{Generated, Rng},
+    parse, Param, ParseError, Solution,
+};
+
+fn mix(secret: u64, value: u64) -> u64 {
+    secret ^ value
+}
+
+const MODULUS: u64 = 16777216;
+
+fn prune(secret: u64) -> u64 {
+    secret % MODULUS
+}
+
+fn next_secret(mut secret: u64) -> u64 {
+    secret = prune(mix(secret, 64 * secret));
+    secret = prune(mix(secret, secret / 32));
+    secret = prune(mix(secret, 2048 * secret));
+    secret
+}
+
+fn secret_nth(secret: u64, n: usize) -> u64 {
+    (0..n).fold(secret, |secret, _| next_secret(secret))
+}
+
+fn price_changes(secret: u64, changes: usize) -> Vec<(u64, i8)> {
+    successors(Some(secret), |&secret| Some(next_secret(secret)))
+        .map(|s| i8::try_from(s.to_string().as_bytes().last().unwrap() - 48).unwrap())
+        .scan(0, |st, price| {
+            let res = price - *st;
+            *st = price;
+            Some((price.try_into().unwrap(), res))
+        })
+        .skip(1)
+        .take(changes)
+        .collect()
+}
+
+fn part2(parsed: &[u64], changes: usize) -> u64 {
+    parsed
+        .iter()
+        .flat_map(|&secret| {
+            price_changes(secret, changes)
+                .windows(4)
+                .map(|w| ([w[0].1, w[1].1, w[2].1, w[3].1], w[3].0))
+                .fold(HashMap::new(), |mut acc, (k, v)| {
+                    acc.entry(k).or_insert(v);
+                    acc
+                })
+        })
+        .fold(HashMap::new(), |mut acc, (k, v)| {
+            *acc.entry(k).or_default() += v;
+            acc
+        })
+        .values()
+        .max()
+        .cloned()
+        .unwrap_or(0)
+}
+
+pub struct Day22 {
+    pub secrets: usize,
+}
+
+impl Default for Day22 {
+    fn default() -> Day22 {
+        Day22 { secrets: 2000 }
+    }
+}
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 22;
+
+    type Parsed<'a> = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>, ParseError> {
+        parse::lines(input)
+            .map(|l| match parse::number(input, l)? {
+                secret if secret < MODULUS => Ok(secret),
+                _ => Err(ParseError::new(input, l, "a secret below 16777216")),
+            })
+            .collect()
+    }
+
+    fn part1(&self, parsed: &Vec<u64>) -> u64 {
+        parsed
+            .iter()
+            .map(|&secret| secret_nth(secret, self.secrets))
+            .sum::<u64>()
+    }
+
+    fn part2(&self, parsed: &Vec<u64>) -> u64 {
+        part2(parsed, self.secrets)
+    }
+
+    fn params_mut(&mut self) -> Vec<(&'static str, &mut dyn Param)> {
+        vec![("secrets", &mut self.secrets)]
+    }
+
+    /// `size` buyers with random initial secrets.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let secrets = (0..size.max(1))
+            .map(|_| format!("{}\n", rng.range(1..=16777215)))
+            .collect::<String>();
+        Some(Generated::new(secrets))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::{mix, part2, price_changes, prune, secret_nth, Day22};
+
+    #[test]
+    fn test_prune() {
+        assert_eq!(prune(100000000), 16113920)
+    }
+
+    #[test]
+    fn test_mix() {
+        assert_eq!(mix(42, 15), 37)
+    }
+
+    #[test]
+    fn test_secret_nth() {
+        for (secret, expected) in [
+            (1, 8685429),
+            (10, 4700978),
+            (100, 15273692),
+            (2024, 8667524),
+        ] {
+            assert_eq!(secret_nth(secret, 2000), expected)
+        }
+    }
+
+    #[test]
+    fn test_price_changes() {
+        assert_eq!(
+            price_changes(123, 9),
+            vec![
+                (0, -3),
+                (6, 6),
+                (5, -1),
+                (4, -1),
+                (4, 0),
+                (6, 2),
+                (4, -2),
+                (4, 0),
+                (2, -2),
+            ]
+        )
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&[1, 2, 3, 2024], 2000), 23)
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day22::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day22::default(), &Part::BOTH, &[1, 20]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day22::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            100,
+        );
+    }
+}
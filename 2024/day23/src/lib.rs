@@ -0,0 +1,178 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    hash::Hash,
+    iter::successors,
+};
+
+use aoc::{
+    generate::{Generated, Rng},
+    parse, ParseError, Part, Solution,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, PartialOrd, Ord)]
+pub struct Vertex<'a>(&'a str);
+
+#[derive(Debug)]
+pub struct Edge<'a>(Vertex<'a>, Vertex<'a>);
+
+fn parse(puzzle: &str) -> Result<(HashSet<Vertex<'_>>, Vec<Edge<'_>>), ParseError> {
+    let mut vs = HashSet::new();
+    let mut es = Vec::new();
+    for s in parse::lines(puzzle) {
+        let (v1r, v2r) = parse::split_once(puzzle, s, "-")?;
+        if v1r == v2r {
+            return Err(ParseError::new(puzzle, v2r, "a different computer"));
+        }
+        let v1 = Vertex(v1r);
+        let v2 = Vertex(v2r);
+        vs.insert(v1);
+        vs.insert(v2);
+        es.push(Edge(v1, v2));
+        es.push(Edge(v2, v1));
+    }
+    Ok((vs, es))
+}
+
+fn neighbours<'a>(v: Vertex<'a>, es: &[Edge<'a>]) -> HashSet<Vertex<'a>> {
+    es.iter().filter(|e| e.0 == v).map(|e| e.1).collect()
+}
+
+fn get_triples<'a>(vs: &HashSet<Vertex<'a>>, es: &[Edge<'a>]) -> HashSet<[Vertex<'a>; 3]> {
+    let mut triples = HashSet::new();
+    for &v in vs {
+        let v_neighbours = neighbours(v, es);
+        for &n in &v_neighbours {
+            let n_neighbours = neighbours(n, es);
+            for &n2 in v_neighbours.intersection(&n_neighbours) {
+                let mut tmp = [v, n, n2];
+                tmp.sort();
+                triples.insert(tmp);
+            }
+        }
+    }
+    triples
+}
+
+fn get_one_more<'a>(
+    sets: &HashMap<Vec<Vertex<'a>>, HashSet<Vertex<'a>>>,
+    cached_neighbours: &HashMap<Vertex<'a>, HashSet<Vertex<'a>>>,
+) -> Option<HashMap<Vec<Vertex<'a>>, HashSet<Vertex<'a>>>> {
+    let new_sets = sets
+        .iter()
+        .flat_map(|(s, intersection)| {
+            intersection.iter().map(|&inter| {
+                let mut new_s = s.clone();
+                new_s.push(inter);
+                new_s.sort();
+                let new_intersection = intersection
+                    .intersection(&cached_neighbours[&inter])
+                    .cloned()
+                    .collect();
+                (new_s, new_intersection)
+            })
+        })
+        .collect::<HashMap<_, _>>();
+    Some(new_sets).filter(|i| !i.is_empty())
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 23;
+
+    type Parsed<'a> = (HashSet<Vertex<'a>>, Vec<Edge<'a>>);
+    type Part1 = usize;
+    type Part2 = Result<Vec<String>, &'static str>;
+
+    fn parse<'a>(
+        &self,
+        input: &'a str,
+    ) -> Result<(HashSet<Vertex<'a>>, Vec<Edge<'a>>), ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, (vs, es): &(HashSet<Vertex>, Vec<Edge>)) -> usize {
+        get_triples(vs, es)
+            .iter()
+            .filter(|component| component.iter().any(|computer| computer.0.starts_with('t')))
+            .count()
+    }
+
+    fn part2(&self, (vs, es): &(HashSet<Vertex>, Vec<Edge>)) -> Result<Vec<String>, &'static str> {
+        let cached_neighbours = vs.iter().map(|&v| (v, neighbours(v, es))).collect();
+        let first = Some(vs.iter().map(|&v| (vec![v], neighbours(v, es))).collect());
+        successors(first, |sets| get_one_more(sets, &cached_neighbours))
+            .last()
+            .unwrap()
+            .into_iter()
+            .next()
+            .map(|(party, _)| party.iter().map(|v| v.0.to_string()).collect())
+            .ok_or("there are no computers")
+    }
+
+    /// `size` computers, at least 14 and at most 676, with a LAN party of 13
+    /// planted among sparse random connections. The party is the known
+    /// answer to part 2.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let mut names = (b'a'..=b'z')
+            .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut names);
+        names.truncate(size.clamp(14, names.len()));
+        let mut connections = BTreeSet::new();
+        for (index, a) in names[..13].iter().enumerate() {
+            for b in &names[index + 1..13] {
+                connections.insert((a.min(b), a.max(b)));
+            }
+        }
+        for a in &names {
+            for _ in 0..2 {
+                let b = rng.choose(&names);
+                if a != b {
+                    connections.insert((a.min(b), a.max(b)));
+                }
+            }
+        }
+        let mut connections = connections
+            .into_iter()
+            .map(|(a, b)| {
+                let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+                format!("{}-{}\n", a, b)
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut connections);
+        let mut party = names[..13].to_vec();
+        party.sort();
+        Some(Generated::new(connections.concat()).known(Part::Two, party))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day23;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day23,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day23, &Part::BOTH, &[14, 100]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day23,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
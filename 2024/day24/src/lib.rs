@@ -0,0 +1,407 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::{
+    generate::{Generated, Rng},
+    parse, ParseError, Part, Solution,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Op {
+    Xor,
+    Or,
+    And,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Input {
+    a: String,
+    b: String,
+    op: Op,
+    out: String,
+}
+
+fn parse_op(puzzle: &str, o: &str) -> Result<Op, ParseError> {
+    match o {
+        "XOR" => Ok(Op::Xor),
+        "OR" => Ok(Op::Or),
+        "AND" => Ok(Op::And),
+        _ => Err(ParseError::new(puzzle, o, "`XOR`, `OR` or `AND`")),
+    }
+}
+
+type Circuit = (HashMap<String, u64>, Vec<Input>);
+
+fn parse(puzzle: &str) -> Result<Circuit, ParseError> {
+    let mut blocks = parse::blocks(puzzle);
+    let start = blocks.next().unwrap_or_default();
+    let ops = blocks.next().ok_or_else(|| {
+        ParseError::new(puzzle, &puzzle[puzzle.len()..], "a blank line and gates")
+    })?;
+    let start_parsed = start
+        .lines()
+        .map(|s| {
+            let (name, value) = parse::split_once(puzzle, s, ": ")?;
+            let value = match value {
+                "0" => 0,
+                "1" => 1,
+                _ => return Err(ParseError::new(puzzle, value, "`0` or `1`")),
+            };
+            Ok((name.to_owned(), value))
+        })
+        .collect::<Result<_, _>>()?;
+    let ops_parsed = ops
+        .lines()
+        .map(|l| {
+            let (a, l) = parse::split_once(puzzle, l, " ")?;
+            let (op, l) = parse::split_once(puzzle, l, " ")?;
+            let (b, out) = parse::split_once(puzzle, l, " -> ")?;
+            Ok(Input {
+                a: a.to_owned(),
+                b: b.to_owned(),
+                op: parse_op(puzzle, op)?,
+                out: out.to_owned(),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((start_parsed, ops_parsed))
+}
+
+fn find<'a>(ops: &'a [Input], state: &HashMap<String, u64>) -> Option<&'a Input> {
+    ops.iter().find(|inp| {
+        !state.contains_key(&inp.out) && state.contains_key(&inp.a) && state.contains_key(&inp.b)
+    })
+}
+
+fn operate(a: u64, b: u64, op: Op) -> u64 {
+    match op {
+        Op::Xor => a ^ b,
+        Op::Or => a | b,
+        Op::And => a & b,
+    }
+}
+
+fn put_together(prefix: &str, state: &HashMap<String, u64>) -> Option<u64> {
+    let mut vec = state
+        .iter()
+        .filter(|(k, _)| k.starts_with(prefix))
+        .collect::<Vec<_>>();
+    vec.sort();
+    vec.into_iter()
+        .enumerate()
+        .try_fold(0u64, |acc, (idx, (_, value))| {
+            Some(acc + value.checked_shl(idx.try_into().ok()?)?)
+        })
+}
+
+fn update_state(state: &mut HashMap<String, u64>, ops: &[Input]) {
+    while let Some(input) = find(ops, state) {
+        state.insert(
+            input.out.clone(),
+            operate(state[&input.a], state[&input.b], input.op),
+        );
+    }
+}
+
+fn part1((state, ops): &Circuit) -> Result<u64, &'static str> {
+    let mut state = state.clone();
+    update_state(&mut state, ops);
+    put_together("z", &state).ok_or("the output has more than 64 bits")
+}
+
+fn make_name(prefix: &str, index: usize) -> String {
+    format!("{}{:02}", prefix, index)
+}
+
+fn paths_to(z_name: &str, ops: &[Input]) -> Vec<Vec<Input>> {
+    let mut paths = vec![vec![z_name.to_string()]];
+    let mut finished_paths = Vec::new();
+    while let Some(path) = paths.pop() {
+        let nexts = ops
+            .iter()
+            .filter(|op| op.out == *path.last().unwrap())
+            .collect::<Vec<_>>();
+        if nexts.is_empty() {
+            finished_paths.push(
+                path.iter()
+                    .filter_map(|out| ops.iter().find(|i| i.out == *out))
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            );
+        } else {
+            for op in ops {
+                if op.out == *path.last().unwrap() {
+                    // A wire already on the path closes a cycle.
+                    for n in [op.a.clone(), op.b.clone()] {
+                        if path.contains(&n) {
+                            continue;
+                        }
+                        let mut p = path.clone();
+                        p.push(n);
+                        paths.push(p);
+                    }
+                }
+            }
+        }
+    }
+    finished_paths.sort_by_key(|v| v.len());
+    finished_paths.dedup();
+    finished_paths
+}
+
+fn swappy(ops: &mut [Input], sws: &[(&str, &str)]) {
+    for &(sw1, sw2) in sws {
+        for op in ops.iter_mut() {
+            if op.out == sw1 {
+                op.out = sw2.to_string();
+            } else if op.out == sw2 {
+                op.out = sw1.to_string();
+            }
+        }
+    }
+}
+
+const NOT_REPAIRED: &str = "the swaps found for the real input do not repair this circuit";
+
+/// Whether the swaps turn the circuit into an adder, judged by the shortest
+/// path to each output bit.
+fn check_repair(ops: &[Input], sws: &[(&str, &str)]) -> Result<(), &'static str> {
+    let mut ops = ops.to_vec();
+    swappy(&mut ops, sws);
+    for index in 0..=45 {
+        let z_name = make_name("z", index);
+        let finished_paths = paths_to(&z_name, &ops);
+        if (3..45).contains(&index) {
+            let head = finished_paths.first().ok_or(NOT_REPAIRED)?;
+            let [output, input, ..] = &head[..] else {
+                return Err(NOT_REPAIRED);
+            };
+
+            //  The shortest input should be x xor y
+            if input.op != Op::Xor
+                || !((input.a == make_name("x", index) && input.b == make_name("y", index))
+                    || (input.a == make_name("y", index) && input.b == make_name("x", index)))
+            {
+                return Err(NOT_REPAIRED);
+            }
+
+            // The output should always be an xor
+            if output.op != Op::Xor {
+                return Err(NOT_REPAIRED);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn part2((_, ops): &Circuit) -> Result<Vec<&'static str>, &'static str> {
+    // Pairs found manually by inspecting the paths to each output bit.
+    // The rules checked in check_repair could be used to write code
+    // that would find these pairs. But meh.
+    let sws = &[
+        ("z06", "fkp"),
+        ("z11", "ngr"),
+        ("z31", "mfm"),
+        ("bpt", "krj"),
+    ];
+    check_repair(ops, sws)?;
+    let mut swapped = sws.iter().flat_map(|p| [p.0, p.1]).collect::<Vec<_>>();
+    swapped.sort();
+    Ok(swapped)
+}
+
+fn random_adder(rng: &mut Rng, size: usize) -> Generated {
+    let bits = size.clamp(6, 63);
+    let mut names = HashSet::new();
+    // Anything but x, y and z, which start the names of inputs and outputs.
+    let mut name = |rng: &mut Rng| loop {
+        let name = (0..3)
+            .map(|_| (b'a' + rng.index(23) as u8) as char)
+            .collect::<String>();
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+    let mut gates = vec![
+        (
+            "x00".to_string(),
+            Op::Xor,
+            "y00".to_string(),
+            "z00".to_string(),
+        ),
+        ("x00".to_string(), Op::And, "y00".to_string(), name(rng)),
+    ];
+    let mut swaps = Vec::new();
+    for bit in 1..bits {
+        let (x, y, z) = (
+            make_name("x", bit),
+            make_name("y", bit),
+            make_name("z", bit),
+        );
+        let carry_in = gates.last().unwrap().3.clone();
+        let (partial, generated, propagated) = (name(rng), name(rng), name(rng));
+        let carry_out = if bit == bits - 1 {
+            make_name("z", bits)
+        } else {
+            name(rng)
+        };
+        gates.push((x.clone(), Op::Xor, y.clone(), partial.clone()));
+        gates.push((x, Op::And, y, generated.clone()));
+        gates.push((partial.clone(), Op::Xor, carry_in.clone(), z.clone()));
+        gates.push((partial.clone(), Op::And, carry_in, propagated.clone()));
+        gates.push((
+            generated.clone(),
+            Op::Or,
+            propagated.clone(),
+            carry_out.clone(),
+        ));
+        // Swaps within a bit that keep the circuit free of cycles.
+        swaps.push(match rng.index(4) {
+            0 => (z, propagated),
+            1 => (z, generated),
+            2 => (z, carry_out),
+            _ => (partial, generated),
+        });
+    }
+    // Not in the last bit, whose carry out is an output.
+    swaps.truncate(bits - 2);
+    rng.shuffle(&mut swaps);
+    swaps.truncate(4);
+    for (_, _, _, out) in &mut gates {
+        if let Some((a, b)) = swaps.iter().find(|(a, b)| out == a || out == b) {
+            *out = if out == a { b.clone() } else { a.clone() };
+        }
+    }
+    rng.shuffle(&mut gates);
+
+    let mut input = String::new();
+    for prefix in ["x", "y"] {
+        for bit in 0..bits {
+            input += &format!("{}: {}\n", make_name(prefix, bit), rng.index(2));
+        }
+    }
+    input.push('\n');
+    for (a, op, b, out) in gates {
+        let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        let op = match op {
+            Op::Xor => "XOR",
+            Op::Or => "OR",
+            Op::And => "AND",
+        };
+        input += &format!("{} {} {} -> {}\n", a, op, b, out);
+    }
+    let mut swapped = swaps
+        .into_iter()
+        .flat_map(|(a, b)| [a, b])
+        .collect::<Vec<_>>();
+    swapped.sort();
+    Generated::new(input).known(Part::Two, swapped)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 24;
+
+    type Parsed<'a> = Circuit;
+    type Part1 = Result<u64, &'static str>;
+    type Part2 = Result<Vec<&'static str>, &'static str>;
+
+    fn parse(&self, input: &str) -> Result<Circuit, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, circuit: &Circuit) -> Result<u64, &'static str> {
+        part1(circuit)
+    }
+
+    fn part2(&self, circuit: &Circuit) -> Result<Vec<&'static str>, &'static str> {
+        part2(circuit)
+    }
+
+    /// A ripple carry adder of `size` bits, at least 6 and at most 63, with
+    /// the outputs of four pairs of gates swapped. The swapped wires are the
+    /// known answer to part 2, although part 2 only solves the real input.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        Some(random_adder(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use aoc::{generate::Rng, Answer, Part};
+
+    use crate::{make_name, parse, put_together, random_adder, swappy, update_state, Day24, Input};
+
+    #[test]
+    fn test_examples() {
+        let examples = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+        aoc::examples::assert_examples(&mut Day24, examples);
+    }
+
+    #[test]
+    fn test_generated() {
+        // Part 2 only knows the swaps in the real input.
+        aoc::generate::assert_generated(&Day24, &[Part::One], &[6, 45]);
+    }
+
+    /// Every way to split `wires` into pairs.
+    fn pairings<'a>(wires: &[&'a str]) -> Vec<Vec<(&'a str, &'a str)>> {
+        let Some((&first, rest)) = wires.split_first() else {
+            return vec![Vec::new()];
+        };
+        let mut pairings = Vec::new();
+        for i in 0..rest.len() {
+            let mut others = rest.to_vec();
+            let second = others.remove(i);
+            for mut pairing in self::pairings(&others) {
+                pairing.push((first, second));
+                pairings.push(pairing);
+            }
+        }
+        pairings
+    }
+
+    fn adds(rng: &mut Rng, ops: &[Input], bits: usize) -> bool {
+        let (x, y) = (rng.below(1 << bits), rng.below(1 << bits));
+        let mut state = HashMap::new();
+        for bit in 0..bits {
+            state.insert(make_name("x", bit), x >> bit & 1);
+            state.insert(make_name("y", bit), y >> bit & 1);
+        }
+        update_state(&mut state, ops);
+        put_together("z", &state) == Some(x + y)
+    }
+
+    #[test]
+    fn test_planted_swaps() {
+        // Undoing the planted swaps, and no other pairing of the same wires,
+        // turns the circuit back into an adder.
+        let mut rng = Rng::new(24);
+        for bits in [6, 6, 45, 45] {
+            let generated = random_adder(&mut rng, bits);
+            let (_, ops) = parse(&generated.input).unwrap();
+            let [(Part::Two, Answer::List(swapped))] = &generated.known[..] else {
+                panic!("no swaps in {:?}", generated.known);
+            };
+            let swapped = swapped.iter().map(String::as_str).collect::<Vec<_>>();
+            let repairs = pairings(&swapped)
+                .into_iter()
+                .filter(|sws| {
+                    let mut ops = ops.clone();
+                    swappy(&mut ops, sws);
+                    (0..20).all(|_| adds(&mut rng, &ops, bits))
+                })
+                .count();
+            assert_eq!(repairs, 1);
+        }
+    }
+
+    #[test]
+    fn test_fuzz() {
+        let examples = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+        aoc::fuzz::assert_total(&mut Day24, examples, 1000);
+    }
+}
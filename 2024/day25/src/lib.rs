@@ -0,0 +1,124 @@
+use aoc::{
+    generate::{Generated, Rng},
+    parse, ParseError, Solution,
+};
+
+type Schematics = (Vec<[u8; 5]>, Vec<[u8; 5]>);
+
+fn parse(puzzle: &str) -> Result<Schematics, ParseError> {
+    let (mut locks, mut keys) = (Vec::new(), Vec::new());
+
+    parse::charset(puzzle, "#.")?;
+    for schematic in parse::blocks(puzzle) {
+        let rows = schematic.lines().collect::<Vec<_>>();
+        if let Some(row) = rows.iter().find(|row| row.len() != 5) {
+            return Err(ParseError::new(puzzle, row, "a row of 5 pins"));
+        }
+        if rows.len() != 7 || !["#####", "....."].contains(&rows[0]) {
+            return Err(ParseError::new(
+                puzzle,
+                schematic,
+                "a lock or key of 7 rows",
+            ));
+        }
+        let mut heights = [0; 5];
+        rows[1..6]
+            .iter()
+            .flat_map(|row| row.bytes().enumerate())
+            .filter(|(_, b)| *b == b'#')
+            .for_each(|(x, _)| heights[x] += 1);
+        if rows[0] == "#####" {
+            locks.push(heights);
+        } else {
+            keys.push(heights);
+        }
+    }
+
+    Ok((locks, keys))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 25;
+
+    type Parsed<'a> = Schematics;
+    type Part1 = usize;
+    type Part2 = &'static str;
+
+    fn parse(&self, input: &str) -> Result<Schematics, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, (locks, keys): &Schematics) -> usize {
+        locks
+            .iter()
+            .flat_map(|l| keys.iter().map(move |k| (l, k)))
+            .filter(|(l, k)| l.iter().zip(k.iter()).all(|(lh, kh)| lh + kh <= 5))
+            .count()
+    }
+
+    // There is no second puzzle on the last day, the star comes for free.
+    fn part2(&self, _: &Schematics) -> &'static str {
+        "Merry Christmas!"
+    }
+
+    /// `size` locks and keys with random pin heights.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        let schematics = (0..size.max(1)).map(|_| {
+            let heights = (0..5).map(|_| rng.range(0..=5)).collect::<Vec<_>>();
+            let lock = rng.chance(0.5);
+            (0..7)
+                .map(|row| {
+                    let pins = heights
+                        .iter()
+                        .map(|&height| {
+                            let filled = if lock {
+                                row <= height
+                            } else {
+                                row >= 6 - height
+                            };
+                            if filled {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect::<String>();
+                    pins + "\n"
+                })
+                .collect::<String>()
+        });
+        Some(Generated::new(schematics.collect::<Vec<_>>().join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use crate::Day25;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::assert_examples(
+            &mut Day25,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        );
+    }
+
+    #[test]
+    fn test_generated() {
+        aoc::generate::assert_generated(&Day25, &Part::BOTH, &[1, 50]);
+    }
+
+    #[test]
+    fn test_fuzz() {
+        aoc::fuzz::assert_total(
+            &mut Day25,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+            1000,
+        );
+    }
+}
@@ -0,0 +1,428 @@
+//! Recording simulations frame by frame as an animated GIF, a numbered PPM
+//! sequence or an asciinema cast. Frames are written as they are recorded,
+//! so long simulations never hold more than one frame in memory.
+
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    json,
+    render::{Colour, Render},
+    Position,
+};
+
+enum Sink<'a> {
+    Gif(Box<dyn Write + 'a>),
+    Cast(Box<dyn Write + 'a>),
+    Ppm(PathBuf),
+}
+
+/// Collects the frames of a simulation. Solutions call [`Recorder::record`]
+/// for every state, and the recorder only draws the ones it keeps.
+pub struct Recorder<'a> {
+    sink: Sink<'a>,
+    every: usize,
+    crop: Option<(Position, i64, i64)>,
+    scale: usize,
+    delay: Duration,
+    seen: usize,
+    written: usize,
+    error: Option<io::Error>,
+}
+
+impl<'a> Recorder<'a> {
+    fn new(sink: Sink<'a>) -> Recorder<'a> {
+        Recorder {
+            sink,
+            every: 1,
+            crop: None,
+            scale: 4,
+            delay: Duration::from_millis(100),
+            seen: 0,
+            written: 0,
+            error: None,
+        }
+    }
+
+    pub fn gif(out: impl Write + 'a) -> Recorder<'a> {
+        Recorder::new(Sink::Gif(Box::new(out)))
+    }
+
+    pub fn cast(out: impl Write + 'a) -> Recorder<'a> {
+        Recorder::new(Sink::Cast(Box::new(out)))
+    }
+
+    /// Writes `frame00000.ppm` and onwards into `dir`, creating it if needed.
+    pub fn ppm_sequence(dir: impl Into<PathBuf>) -> io::Result<Recorder<'a>> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Recorder::new(Sink::Ppm(dir)))
+    }
+
+    /// Picks the format from the extension of `path`, `.gif` or `.cast`, and
+    /// otherwise treats it as a directory for a PPM sequence.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Recorder<'a>> {
+        let path = path.as_ref();
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("gif") => Ok(Recorder::gif(BufWriter::new(File::create(path)?))),
+            Some("cast") => Ok(Recorder::cast(BufWriter::new(File::create(path)?))),
+            _ => Recorder::ppm_sequence(path),
+        }
+    }
+
+    /// Keeps only every `n`th frame, starting with the first.
+    pub fn every(mut self, n: usize) -> Recorder<'a> {
+        self.every = n.max(1);
+        self
+    }
+
+    pub fn crop(mut self, origin: Position, width: i64, height: i64) -> Recorder<'a> {
+        self.crop = Some((origin, width, height));
+        self
+    }
+
+    /// Pixels per cell side in images.
+    pub fn scale(mut self, scale: usize) -> Recorder<'a> {
+        self.scale = scale.max(1);
+        self
+    }
+
+    /// How long each frame shows when played back.
+    pub fn delay(mut self, delay: Duration) -> Recorder<'a> {
+        self.delay = delay;
+        self
+    }
+
+    /// How many frames have been offered, kept or not.
+    pub fn seen(&self) -> usize {
+        self.seen
+    }
+
+    /// Offers the next frame, drawing it only if it is kept. Write errors are
+    /// kept for [`Recorder::finish`] so simulations need not handle them.
+    pub fn record(&mut self, frame: impl FnOnce() -> Render) {
+        let index = self.seen;
+        self.seen += 1;
+        if !index.is_multiple_of(self.every) || self.error.is_some() {
+            return;
+        }
+        let mut frame = frame();
+        if let Some((origin, width, height)) = self.crop {
+            frame = frame.crop(origin, width, height);
+        }
+        if let Err(e) = self.write(&frame) {
+            self.error = Some(e);
+        }
+    }
+
+    fn write(&mut self, frame: &Render) -> io::Result<()> {
+        let first = self.written == 0;
+        let time = self.delay * self.written as u32;
+        match &mut self.sink {
+            Sink::Gif(out) => {
+                if first {
+                    gif_header(out, frame, self.scale)?;
+                }
+                gif_frame(out, frame, self.scale, self.delay)?;
+            }
+            Sink::Cast(out) => {
+                if first {
+                    let header = json::Value::object([
+                        ("version", 2i64.into()),
+                        ("width", frame.width().into()),
+                        ("height", frame.height().into()),
+                    ]);
+                    writeln!(out, "{}", header)?;
+                }
+                let clear = if first { "\x1b[2J" } else { "" };
+                let text = format!("{}\x1b[H{}", clear, frame.ansi().replace('\n', "\r\n"));
+                let event =
+                    json::Value::Array(vec![time.as_secs_f64().into(), "o".into(), text.into()]);
+                writeln!(out, "{}", event)?;
+            }
+            Sink::Ppm(dir) => {
+                let path = dir.join(format!("frame{:05}.ppm", self.written));
+                fs::write(path, frame.ppm(self.scale))?;
+            }
+        }
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Completes the output, returning how many frames were written.
+    pub fn finish(mut self) -> io::Result<usize> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+        match &mut self.sink {
+            Sink::Gif(out) => {
+                if self.written > 0 {
+                    out.write_all(&[0x3b])?;
+                }
+                out.flush()?;
+            }
+            Sink::Cast(out) => out.flush()?,
+            Sink::Ppm(_) => {}
+        }
+        Ok(self.written)
+    }
+}
+
+/// The frame's width and height in pixels, which a GIF keeps in 16 bits each.
+fn gif_size(frame: &Render, scale: usize) -> io::Result<(u16, u16)> {
+    let pixels = |cells: i64| {
+        (cells as usize)
+            .checked_mul(scale)
+            .and_then(|pixels| u16::try_from(pixels).ok())
+    };
+    match (pixels(frame.width()), pixels(frame.height())) {
+        (Some(width), Some(height)) => Ok((width, height)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "a {} by {} frame at scale {} is over the 65535 pixels a GIF allows",
+                frame.width(),
+                frame.height(),
+                scale
+            ),
+        )),
+    }
+}
+
+fn gif_header(out: &mut impl Write, frame: &Render, scale: usize) -> io::Result<()> {
+    let (width, height) = gif_size(frame, scale)?;
+    out.write_all(b"GIF89a")?;
+    out.write_all(&width.to_le_bytes())?;
+    out.write_all(&height.to_le_bytes())?;
+    // No global colour table, since every frame brings its own.
+    out.write_all(&[0x70, 0, 0])?;
+    // Loop forever.
+    out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")
+}
+
+/// The frame's colours, at most 256, and each cell's index among them, where
+/// any further colours take the index of the closest one.
+fn palette(frame: &Render) -> (Vec<Colour>, Vec<Vec<u8>>) {
+    let mut colours: Vec<Colour> = Vec::new();
+    let indices = frame
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|cell| {
+                    let colour = cell.colour;
+                    match colours.iter().position(|&c| c == colour) {
+                        Some(index) => index as u8,
+                        None if colours.len() < 256 => {
+                            colours.push(colour);
+                            (colours.len() - 1) as u8
+                        }
+                        None => {
+                            let distance = |c: &Colour| {
+                                [(c.r, colour.r), (c.g, colour.g), (c.b, colour.b)]
+                                    .into_iter()
+                                    .map(|(a, b)| (a as i32 - b as i32).pow(2))
+                                    .sum::<i32>()
+                            };
+                            let (index, _) = colours
+                                .iter()
+                                .enumerate()
+                                .min_by_key(|(_, c)| distance(c))
+                                .unwrap();
+                            index as u8
+                        }
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    (colours, indices)
+}
+
+fn gif_frame(
+    out: &mut impl Write,
+    frame: &Render,
+    scale: usize,
+    delay: Duration,
+) -> io::Result<()> {
+    let (width, height) = gif_size(frame, scale)?;
+    let (colours, indices) = palette(frame);
+    let bits = (usize::BITS - colours.len().saturating_sub(1).leading_zeros()).max(1);
+    let centiseconds = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+    // Graphic control: leave each frame in place, then wait.
+    out.write_all(&[0x21, 0xf9, 0x04, 0x04])?;
+    out.write_all(&centiseconds.to_le_bytes())?;
+    out.write_all(&[0, 0])?;
+
+    out.write_all(&[0x2c, 0, 0, 0, 0])?;
+    out.write_all(&width.to_le_bytes())?;
+    out.write_all(&height.to_le_bytes())?;
+    out.write_all(&[0x80 | (bits - 1) as u8])?;
+    for index in 0..1 << bits {
+        let Colour { r, g, b } = colours.get(index).copied().unwrap_or(Colour::BLACK);
+        out.write_all(&[r, g, b])?;
+    }
+
+    let pixels = indices
+        .iter()
+        .flat_map(|row| {
+            let line = row
+                .iter()
+                .flat_map(|&index| std::iter::repeat_n(index, scale))
+                .collect::<Vec<_>>();
+            std::iter::repeat_n(line, scale).flatten()
+        })
+        .collect::<Vec<_>>();
+    let min_code_size = bits.max(2) as u8;
+    out.write_all(&[min_code_size])?;
+    for block in lzw(&pixels, min_code_size).chunks(255) {
+        out.write_all(&[block.len() as u8])?;
+        out.write_all(block)?;
+    }
+    out.write_all(&[0])
+}
+
+/// GIF flavoured LZW: variable width codes of up to 12 bits, packed least
+/// significant bit first, with a clear code whenever the table fills up.
+fn lzw(pixels: &[u8], min_code_size: u8) -> Vec<u8> {
+    use std::collections::HashMap;
+
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let mut out = Vec::new();
+    let (mut buffer, mut buffered) = (0u32, 0u32);
+    let mut emit = |code: u16, size: u32, out: &mut Vec<u8>| {
+        buffer |= (code as u32) << buffered;
+        buffered += size;
+        while buffered >= 8 {
+            out.push(buffer as u8);
+            buffer >>= 8;
+            buffered -= 8;
+        }
+    };
+
+    let mut table = HashMap::<(u16, u8), u16>::new();
+    let mut size = min_code_size as u32 + 1;
+    let mut next = end + 1;
+    emit(clear, size, &mut out);
+    let mut pixels = pixels.iter();
+    if let Some(&first) = pixels.next() {
+        let mut prefix = first as u16;
+        for &pixel in pixels {
+            if let Some(&code) = table.get(&(prefix, pixel)) {
+                prefix = code;
+                continue;
+            }
+            emit(prefix, size, &mut out);
+            table.insert((prefix, pixel), next);
+            next += 1;
+            if next == 4096 {
+                emit(clear, size, &mut out);
+                table.clear();
+                size = min_code_size as u32 + 1;
+                next = end + 1;
+            } else if next > 1 << size {
+                size += 1;
+            }
+            prefix = pixel as u16;
+        }
+        emit(prefix, size, &mut out);
+    }
+    emit(end, size, &mut out);
+    if buffered > 0 {
+        out.push(buffer as u8);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::{lzw, Recorder};
+    use crate::{render::Render, Grid, Position};
+
+    /// A plain GIF LZW decoder to check the encoder against.
+    fn unlzw(data: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1usize << min_code_size;
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut size = min_code_size as usize + 1;
+        let (mut buffer, mut buffered, mut bytes) = (0usize, 0, data.iter());
+        let mut previous: Option<Vec<u8>> = None;
+        let mut out = Vec::new();
+        loop {
+            while buffered < size {
+                buffer |= (*bytes.next().unwrap() as usize) << buffered;
+                buffered += 8;
+            }
+            let code = buffer & ((1 << size) - 1);
+            buffer >>= size;
+            buffered -= size;
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([Vec::new(), Vec::new()]);
+                size = min_code_size as usize + 1;
+                previous = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+            let entry = match (table.get(code), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [&previous[..], &previous[..1]].concat(),
+                (None, None) => panic!("bad code {}", code),
+            };
+            if let Some(previous) = previous {
+                table.push([&previous[..], &entry[..1]].concat());
+                if table.len() == 1 << size && size < 12 {
+                    size += 1;
+                }
+            }
+            out.extend(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw() {
+        let pixels = (0..20000u32)
+            .map(|i| ((i * i / 7 + i / 13) % 5) as u8)
+            .collect::<Vec<_>>();
+        assert_eq!(unlzw(&lzw(&pixels, 3), 3), pixels);
+        assert_eq!(unlzw(&lzw(&[], 2), 2), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_recorder() {
+        let grid = Grid::parse("#.\n.E\n").unwrap();
+        let mut cast = Vec::new();
+        let mut recorder = Recorder::cast(&mut cast)
+            .every(2)
+            .crop(Position::new(0, 1), 2, 1);
+        for _ in 0..5 {
+            recorder.record(|| Render::map(&grid));
+        }
+        assert_eq!(recorder.seen(), 5);
+        assert_eq!(recorder.finish().unwrap(), 3);
+        let cast = String::from_utf8(cast).unwrap();
+        let lines = cast.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], r#"{"version":2,"width":2,"height":1}"#);
+        assert!(lines[3].starts_with(r#"[0.2,"o","\u001b[H"#));
+
+        let mut gif = Vec::new();
+        let mut recorder = Recorder::gif(&mut gif).scale(2);
+        recorder.record(|| Render::map(&grid));
+        assert_eq!(recorder.finish().unwrap(), 1);
+        assert!(gif.starts_with(b"GIF89a\x04\x00\x04\x00"));
+        assert_eq!(gif.last(), Some(&0x3b));
+
+        let mut recorder = Recorder::gif(Vec::new()).scale(40000);
+        recorder.record(|| Render::map(&grid));
+        let e = recorder.finish().unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+    }
+}
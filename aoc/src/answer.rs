@@ -0,0 +1,148 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{json, Position};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    String(String),
+    List(Vec<String>),
+    Position(Position),
+}
+
+impl Answer {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::String(_) => "string",
+            Answer::List(_) => "list",
+            Answer::Position(_) => "position",
+        }
+    }
+
+    pub fn to_json(&self) -> json::Value {
+        let value = match self {
+            Answer::Integer(i) => json::Value::Integer(*i),
+            Answer::String(s) => s.as_str().into(),
+            Answer::List(items) => {
+                json::Value::Array(items.iter().map(|item| item.as_str().into()).collect())
+            }
+            Answer::Position(Position { x, y }) => {
+                json::Value::object([("x", (*x).into()), ("y", (*y).into())])
+            }
+        };
+        json::Value::object([("type", self.kind().into()), ("value", value)])
+    }
+}
+
+/// The format the puzzle website expects, so lists are comma separated and
+/// positions are `x,y`.
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(i) => write!(f, "{}", i),
+            Answer::String(s) => f.write_str(s),
+            Answer::List(items) => f.write_str(&items.join(",")),
+            Answer::Position(Position { x, y }) => write!(f, "{},{}", x, y),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(i: $t) -> Answer {
+                    Answer::Integer(i as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::String(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::String(s.to_string())
+    }
+}
+
+impl<T: Display> From<Vec<T>> for Answer {
+    fn from(items: Vec<T>) -> Answer {
+        Answer::List(items.iter().map(ToString::to_string).collect())
+    }
+}
+
+impl From<Position> for Answer {
+    fn from(position: Position) -> Answer {
+        Answer::Position(position)
+    }
+}
+
+/// What a part returns: anything that converts to an [`Answer`], or a
+/// `Result` of one for parts that find no answer in some inputs.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, String>;
+}
+
+macro_rules! into_answer {
+    ($($t:ty),*) => {
+        $(
+            impl IntoAnswer for $t {
+                fn into_answer(self) -> Result<Answer, String> {
+                    Ok(self.into())
+                }
+            }
+        )*
+    };
+}
+
+into_answer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, String, &str, Position);
+
+impl<T: Display> IntoAnswer for Vec<T> {
+    fn into_answer(self) -> Result<Answer, String> {
+        Ok(self.into())
+    }
+}
+
+impl<T: IntoAnswer, E: Display> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<Answer, String> {
+        self.map_err(|e| e.to_string())?.into_answer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+    use crate::Position;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(1234u64).to_string(), "1234");
+        assert_eq!(Answer::from(vec![4, 6, 3]).to_string(), "4,6,3");
+        assert_eq!(Answer::from(Position { x: 6, y: 1 }).to_string(), "6,1");
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            Answer::from(-12i64).to_json().to_string(),
+            r#"{"type":"integer","value":-12}"#
+        );
+        assert_eq!(
+            Answer::from(vec!["co", "de"]).to_json().to_string(),
+            r#"{"type":"list","value":["co","de"]}"#
+        );
+        assert_eq!(
+            Answer::from(Position { x: 6, y: 1 }).to_json().to_string(),
+            r#"{"type":"position","value":{"x":6,"y":1}}"#
+        );
+    }
+}
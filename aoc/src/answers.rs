@@ -0,0 +1,127 @@
+use std::{fs, io, path::Path, time::Duration};
+
+use crate::{
+    toml::{Document, Value},
+    Answer, Part,
+};
+
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+/// FNV-1a, which is enough to tell one account's input from another's.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Accepted {
+    pub answer: String,
+    pub millis: Option<f64>,
+}
+
+/// The accepted answers per year, day and input, kept in a TOML file with
+/// one `[YYYY.dayNN.<input hash>]` table holding `part1`, `part2`,
+/// `part1_ms` and `part2_ms`.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    document: Document,
+}
+
+fn table_name(year: u16, day: u8, hash: &str) -> String {
+    format!("{}.day{:02}.{}", year, day, hash)
+}
+
+impl Answers {
+    /// Reads `path`, which may not exist yet.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Answers> {
+        let path = path.as_ref();
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let document = Document::parse(&text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })?;
+        Ok(Answers { document })
+    }
+
+    pub fn save(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.document.sort_tables();
+        fs::write(path, self.document.to_string())
+    }
+
+    pub fn get(&self, year: u16, day: u8, hash: &str, part: Part) -> Option<Accepted> {
+        let table = self.document.table(&table_name(year, day, hash))?;
+        let key = format!("part{}", part.number());
+        let answer = match table.get(&key)? {
+            Value::String(s) => s.clone(),
+            value => value.to_string(),
+        };
+        let millis = table.get(&format!("{}_ms", key)).and_then(Value::as_float);
+        Some(Accepted { answer, millis })
+    }
+
+    pub fn accept(
+        &mut self,
+        year: u16,
+        day: u8,
+        hash: &str,
+        part: Part,
+        answer: &Answer,
+        time: Duration,
+    ) {
+        let table = self.document.table_mut(&table_name(year, day, hash));
+        let key = format!("part{}", part.number());
+        match answer {
+            Answer::Integer(i) => table.insert(&key, Value::Integer(*i)),
+            answer => table.insert(&key, answer.to_string()),
+        }
+        let millis = (time.as_secs_f64() * 1e6).round() / 1e3;
+        table.insert(format!("{}_ms", key), millis);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{input_hash, Accepted, Answers};
+    use crate::{toml::Document, Answer, Part};
+
+    #[test]
+    fn test_accept() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        let hash = input_hash("3   4\n");
+        let mut answers = Answers::default();
+        answers.accept(
+            2024,
+            1,
+            &hash,
+            Part::One,
+            &Answer::Integer(11),
+            Duration::from_micros(1500),
+        );
+        answers.accept(2024, 1, &hash, Part::Two, &"co,de".into(), Duration::ZERO);
+        let document = Document::parse(&answers.document.to_string()).unwrap();
+        let answers = Answers { document };
+        assert_eq!(
+            answers.get(2024, 1, &hash, Part::One),
+            Some(Accepted {
+                answer: "11".to_string(),
+                millis: Some(1.5)
+            })
+        );
+        assert_eq!(
+            answers.get(2024, 1, &hash, Part::Two).unwrap().answer,
+            "co,de"
+        );
+        assert_eq!(answers.get(2024, 2, &hash, Part::One), None);
+        assert_eq!(answers.get(2023, 1, &hash, Part::One), None);
+    }
+}
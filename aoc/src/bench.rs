@@ -0,0 +1,128 @@
+use std::{fs, io, path::Path, time::Duration};
+
+use crate::{answers::input_hash, toml::Document};
+
+/// Running times of each phase of a solution, one entry per iteration.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+impl Timings {
+    pub const PHASES: [&'static str; 3] = ["parse", "part1", "part2"];
+
+    pub fn summaries(&self) -> [(&'static str, Summary); 3] {
+        [
+            (Timings::PHASES[0], Summary::of(&self.parse)),
+            (Timings::PHASES[1], Summary::of(&self.part1)),
+            (Timings::PHASES[2], Summary::of(&self.part2)),
+        ]
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Summary {
+    pub fn of(times: &[Duration]) -> Summary {
+        let mut sorted = times.to_vec();
+        sorted.sort();
+        match sorted.len() {
+            0 => Summary::default(),
+            len => Summary {
+                min: sorted[0],
+                median: sorted[len / 2],
+                max: sorted[len - 1],
+            },
+        }
+    }
+}
+
+pub fn millis(time: Duration) -> f64 {
+    time.as_secs_f64() * 1e3
+}
+
+/// Median times from an earlier run, in a TOML file with one `[YYYY.dayNN]`
+/// table holding the input hash and `<phase>_ms` for every phase.
+#[derive(Clone, Debug, Default)]
+pub struct Baseline {
+    document: Document,
+}
+
+fn table_name(year: u16, day: u8) -> String {
+    format!("{}.day{:02}", year, day)
+}
+
+impl Baseline {
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Baseline> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+        let document = Document::parse(&text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })?;
+        Ok(Baseline { document })
+    }
+
+    pub fn save(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.document.sort_tables();
+        fs::write(path, self.document.to_string())
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, input: &str, timings: &Timings) {
+        let table = self.document.table_mut(&table_name(year, day));
+        table.insert("input", input_hash(input));
+        for (phase, summary) in timings.summaries() {
+            let millis = (millis(summary.median) * 1e3).round() / 1e3;
+            table.insert(format!("{}_ms", phase), millis);
+        }
+    }
+
+    /// The median of `phase` in milliseconds, if it was measured on the same
+    /// input.
+    pub fn median(&self, year: u16, day: u8, input: &str, phase: &str) -> Option<f64> {
+        let table = self.document.table(&table_name(year, day))?;
+        if table.get("input")?.as_str()? != input_hash(input) {
+            return None;
+        }
+        table.get(&format!("{}_ms", phase))?.as_float()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Baseline, Summary, Timings};
+
+    #[test]
+    fn test_baseline() {
+        let ms = Duration::from_millis;
+        let summary = Summary::of(&[ms(3), ms(1), ms(2), ms(9)]);
+        assert_eq!(
+            (summary.min, summary.median, summary.max),
+            (ms(1), ms(3), ms(9))
+        );
+
+        let timings = Timings {
+            parse: vec![ms(1)],
+            part1: vec![ms(2), ms(4), ms(3)],
+            part2: vec![],
+        };
+        let mut baseline = Baseline::default();
+        baseline.record(2024, 6, "input", &timings);
+        assert_eq!(baseline.median(2024, 6, "input", "part1"), Some(3.0));
+        assert_eq!(baseline.median(2024, 6, "other input", "part1"), None);
+        assert_eq!(baseline.median(2024, 7, "input", "part1"), None);
+        assert_eq!(baseline.median(2023, 6, "input", "part1"), None);
+    }
+}
@@ -0,0 +1,192 @@
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+/// Hit and miss counters of a [`Cache`], together with its current size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub size: usize,
+}
+
+struct Entries<K, V> {
+    values: HashMap<K, (V, u64)>,
+    // Last use of every key, oldest first. Only kept when there is a capacity.
+    recency: BTreeMap<u64, K>,
+    capacity: Option<usize>,
+    clock: u64,
+    stats: CacheStats,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Entries<K, V> {
+    fn get<Q>(&mut self, q: &Q) -> Option<V>
+    where
+        Q: ?Sized + Eq + Hash,
+        K: Borrow<Q>,
+    {
+        self.clock += 1;
+        let (value, used) = self.values.get_mut(q)?;
+        if self.capacity.is_some() {
+            let key = self.recency.remove(used).unwrap();
+            self.recency.insert(self.clock, key);
+        }
+        *used = self.clock;
+        self.stats.hits += 1;
+        Some(value.clone())
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        // A tick of its own, as the last one may belong to the key a
+        // recursive call just looked up.
+        self.clock += 1;
+        if let Some(capacity) = self.capacity {
+            while self.values.len() >= capacity.max(1) {
+                let (_, oldest) = self.recency.pop_first().unwrap();
+                self.values.remove(&oldest);
+                self.stats.evictions += 1;
+            }
+            self.recency.insert(self.clock, key.clone());
+        }
+        self.values.insert(key, (value, self.clock));
+    }
+}
+
+/// Memoizes `f`, which may call back into the cache through the function it
+/// is handed in order to define itself recursively.
+///
+/// With a capacity the least recently used entry is evicted first.
+pub struct Cache<K, V, Q: ?Sized, F> {
+    entries: Entries<K, V>,
+    f: F,
+    query: PhantomData<fn(&Q)>,
+}
+
+impl<K, V, Q, F> Cache<K, V, Q, F>
+where
+    Q: ?Sized + Eq + Hash + ToOwned<Owned = K>,
+    K: Clone + Eq + Hash + Borrow<Q>,
+    V: Clone,
+    F: Fn(&Q, &mut dyn FnMut(&Q) -> V) -> V,
+{
+    pub fn new(f: F) -> Cache<K, V, Q, F> {
+        Cache {
+            entries: Entries {
+                values: HashMap::new(),
+                recency: BTreeMap::new(),
+                capacity: None,
+                clock: 0,
+                stats: CacheStats::default(),
+            },
+            f,
+            query: PhantomData,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize, f: F) -> Cache<K, V, Q, F> {
+        let mut cache = Cache::new(f);
+        cache.entries.capacity = Some(capacity);
+        cache
+    }
+
+    pub fn get_or_compute(&mut self, q: &Q) -> V {
+        compute(&mut self.entries, &self.f, q)
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            size: self.entries.values.len(),
+            ..self.entries.stats
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.values.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.values.clear();
+        self.entries.recency.clear();
+    }
+}
+
+fn compute<K, V, Q, F>(entries: &mut Entries<K, V>, f: &F, q: &Q) -> V
+where
+    Q: ?Sized + Eq + Hash + ToOwned<Owned = K>,
+    K: Clone + Eq + Hash + Borrow<Q>,
+    V: Clone,
+    F: Fn(&Q, &mut dyn FnMut(&Q) -> V) -> V,
+{
+    if let Some(value) = entries.get(q) {
+        return value;
+    }
+    entries.stats.misses += 1;
+    let value = f(q, &mut |inner| compute(entries, f, inner));
+    entries.insert(q.to_owned(), value.clone());
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cache, CacheStats};
+
+    #[test]
+    fn test_cache() {
+        let mut fibonacci = Cache::new(|&n: &u64, fibonacci: &mut dyn FnMut(&u64) -> u64| {
+            if n < 2 {
+                n
+            } else {
+                fibonacci(&(n - 1)) + fibonacci(&(n - 2))
+            }
+        });
+        assert_eq!(fibonacci.get_or_compute(&90), 2880067194370816120);
+        assert_eq!(fibonacci.get_or_compute(&90), 2880067194370816120);
+        assert_eq!(
+            fibonacci.stats(),
+            CacheStats {
+                hits: 89,
+                misses: 91,
+                evictions: 0,
+                size: 91
+            }
+        );
+
+        let offset = 10;
+        let mut bounded = Cache::with_capacity(2, |s: &str, _: &mut dyn FnMut(&str) -> usize| {
+            s.len() + offset
+        });
+        bounded.get_or_compute("a");
+        bounded.get_or_compute("bb");
+        bounded.get_or_compute("a");
+        assert_eq!(bounded.get_or_compute("ccc"), 13);
+        assert_eq!(bounded.len(), 2);
+        bounded.get_or_compute("a");
+        bounded.get_or_compute("bb");
+        assert_eq!(bounded.stats().hits, 2);
+        assert_eq!(bounded.stats().evictions, 2);
+    }
+
+    #[test]
+    fn test_bounded_recursion() {
+        let mut small =
+            Cache::with_capacity(3, |&n: &u64, fibonacci: &mut dyn FnMut(&u64) -> u64| {
+                if n < 2 {
+                    n
+                } else {
+                    fibonacci(&(n - 1)) + fibonacci(&(n - 2))
+                }
+            });
+        assert_eq!(small.get_or_compute(&4), 3);
+        assert_eq!(small.get_or_compute(&30), 832040);
+        assert_eq!(small.len(), 3);
+        assert!(small.stats().evictions > 0);
+    }
+}
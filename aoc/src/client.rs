@@ -0,0 +1,473 @@
+//! A client for adventofcode.com that downloads puzzle inputs into a local
+//! cache, so that each input is downloaded once, and submits answers. It
+//! waits between requests so that it never hits the site more often than
+//! [`MIN_INTERVAL`].
+
+use std::{
+    fs, io,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    http::{self, Http, Request, Response},
+    input,
+    submissions::Verdict,
+    Part,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The site asks automated requests to say where they come from.
+const USER_AGENT: &str = "github.com/dahlbaek/aoc2024 by dahlbaek";
+
+/// Holds the time of the last request, in milliseconds since the epoch, so
+/// that the rate limit holds across runs.
+const LAST_REQUEST: &str = ".last-request";
+
+fn not_unlocked(year: u16, day: u8) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("day {} of {} is not unlocked yet", day, year),
+    )
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Submitted {
+    /// A verdict, along with how long to wait before the next answer.
+    Verdict(Verdict, Duration),
+    /// The answer came too soon after the last one and was not looked at.
+    TooSoon(Duration),
+    /// The part was solved already, by an answer not in the history.
+    AlreadySolved,
+}
+
+/// The wait before the next answer after a wrong one, unless the site says.
+const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+impl Submitted {
+    /// Reads the message in the page the site answers with.
+    pub fn parse(page: &str) -> Option<Submitted> {
+        let wait = parse_wait(page);
+        if page.contains("That's the right answer") {
+            Some(Submitted::Verdict(Verdict::Correct, Duration::ZERO))
+        } else if page.contains("That's not the right answer") {
+            let verdict = match () {
+                _ if page.contains("too high") => Verdict::TooHigh,
+                _ if page.contains("too low") => Verdict::TooLow,
+                _ => Verdict::Wrong,
+            };
+            Some(Submitted::Verdict(verdict, wait.unwrap_or(DEFAULT_WAIT)))
+        } else if page.contains("You gave an answer too recently") {
+            Some(Submitted::TooSoon(wait.unwrap_or(DEFAULT_WAIT)))
+        } else if page.contains("Did you already complete it") {
+            Some(Submitted::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+fn number_word(word: &str) -> Option<u64> {
+    const WORDS: [&str; 10] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+    match WORDS.iter().position(|&w| w == word) {
+        Some(index) => Some(index as u64 + 1),
+        None => word.parse().ok(),
+    }
+}
+
+/// The wait in "You have 1m 23s left to wait" or in "Please wait one minute
+/// before trying again".
+fn parse_wait(page: &str) -> Option<Duration> {
+    if let Some(end) = page.find(" left to wait") {
+        let start = page[..end].rfind("You have ")? + "You have ".len();
+        return page[start..end]
+            .split_whitespace()
+            .try_fold(Duration::ZERO, |wait, amount| {
+                let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+                let secs = number.parse::<u64>().ok()?
+                    * match unit {
+                        "h" => 3600,
+                        "m" => 60,
+                        "s" => 1,
+                        _ => return None,
+                    };
+                Some(wait + Duration::from_secs(secs))
+            });
+    }
+    let start = page.find("lease wait ")? + "lease wait ".len();
+    let mut words = page[start..].split_whitespace();
+    let number = number_word(words.next()?)?;
+    match words.next()?.trim_end_matches('s') {
+        "minute" => Some(Duration::from_secs(number * 60)),
+        "second" => Some(Duration::from_secs(number)),
+        _ => None,
+    }
+}
+
+pub struct Client {
+    http: Box<dyn Http>,
+    base_url: String,
+    session: Option<String>,
+    dir: PathBuf,
+    min_interval: Duration,
+}
+
+impl Client {
+    /// A client of the site at `base_url` that caches inputs in `dir`. The
+    /// session token is only needed for inputs that are not cached yet.
+    pub fn new(
+        http: Box<dyn Http>,
+        base_url: impl Into<String>,
+        session: Option<String>,
+        dir: impl Into<PathBuf>,
+    ) -> Client {
+        Client {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session,
+            dir: dir.into(),
+            min_interval: MIN_INTERVAL,
+        }
+    }
+
+    /// A client with the backend for `base_url` that caches inputs where
+    /// `aoc run` looks for them.
+    pub fn with_defaults(base_url: &str, session: Option<String>) -> Client {
+        Client::new(http::backend(base_url), base_url, session, input::INPUT_DIR)
+    }
+
+    pub fn min_interval(mut self, min_interval: Duration) -> Client {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        input::path_in(&self.dir, year, day)
+    }
+
+    fn session(&self, purpose: String) -> io::Result<&str> {
+        self.session.as_deref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("there is no session token to {} with", purpose),
+            )
+        })
+    }
+
+    /// Sends `request` once the rate limit allows, failing on the statuses
+    /// the site answers a missing or expired session with.
+    fn send(&self, request: Request) -> io::Result<Response> {
+        let request = request.header("User-Agent", USER_AGENT);
+        fs::create_dir_all(&self.dir)?;
+        self.throttle()?;
+        let response = self.http.send(&request)?;
+        match response.status {
+            400 | 401 | 403 | 500 => Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!(
+                    "{} answered {}, the session token may have expired",
+                    request.url, response.status
+                ),
+            )),
+            _ => Ok(response),
+        }
+    }
+
+    fn puzzle_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    /// The input of `day` of `year` from the cache, or downloaded into it.
+    pub fn fetch(&self, year: u16, day: u8) -> io::Result<Fetched> {
+        let path = self.input_path(year, day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+        let session = self.session(format!("download day {} of {}", day, year))?;
+        let url = format!("{}/input", self.puzzle_url(year, day));
+        let response =
+            self.send(Request::get(&url).header("Cookie", format!("session={}", session)))?;
+        match response.status {
+            200 if !response.body.is_empty() => {}
+            200 => return Err(io::Error::other(format!("{} is empty", url))),
+            404 => return Err(not_unlocked(year, day)),
+            status => {
+                return Err(io::Error::other(format!(
+                    "{} answered {}: {}",
+                    url,
+                    status,
+                    response.body.trim()
+                )))
+            }
+        }
+        // Written next to the cache and moved in, so that an interrupted
+        // download never looks cached.
+        let partial = path.with_extension("part");
+        fs::create_dir_all(partial.parent().unwrap_or(&self.dir))?;
+        fs::write(&partial, &response.body)?;
+        fs::rename(&partial, &path)?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Submits `answer` to `part` of `day` of `year`, whatever the history of
+    /// earlier attempts says.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> io::Result<Submitted> {
+        let session = self.session(format!(
+            "submit day {} of {} part {}",
+            day,
+            year,
+            part.number()
+        ))?;
+        let url = format!("{}/answer", self.puzzle_url(year, day));
+        let level = part.number().to_string();
+        let request = Request::post_form(&url, &[("level", &level), ("answer", answer)])
+            .header("Cookie", format!("session={}", session));
+        let response = self.send(request)?;
+        match response.status {
+            200 => Submitted::parse(&response.body).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} answered with an unknown message", url),
+                )
+            }),
+            404 => Err(not_unlocked(year, day)),
+            status => Err(io::Error::other(format!("{} answered {}", url, status))),
+        }
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request, and
+    /// records this one.
+    fn throttle(&self) -> io::Result<()> {
+        let path = self.dir.join(LAST_REQUEST);
+        let last = fs::read_to_string(&path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(last) = last {
+            // A last request in the future, after the clock went back, waits
+            // the whole interval.
+            let since = SystemTime::now()
+                .duration_since(last)
+                .unwrap_or(Duration::ZERO);
+            thread::sleep(self.min_interval.saturating_sub(since));
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+        // Rounded up, so that the next request never waits too little.
+        fs::write(path, now.as_nanos().div_ceil(1_000_000).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        env, fs, io,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        path::PathBuf,
+        process,
+        rc::Rc,
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use super::{Client, Fetched, Submitted};
+    use crate::{
+        http::{Http, Request, Response, TcpHttp},
+        submissions::Verdict,
+        Part,
+    };
+
+    struct Stub {
+        status: u16,
+        body: &'static str,
+        requests: RefCell<Vec<Request>>,
+    }
+
+    impl Http for Rc<Stub> {
+        fn send(&self, request: &Request) -> io::Result<Response> {
+            self.requests.borrow_mut().push(request.clone());
+            Ok(Response {
+                status: self.status,
+                body: self.body.to_string(),
+            })
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-client-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn stub(status: u16) -> Rc<Stub> {
+        Rc::new(Stub {
+            status,
+            body: "3   4\n",
+            requests: RefCell::new(Vec::new()),
+        })
+    }
+
+    #[test]
+    fn test_fetch_caches() {
+        let dir = temp_dir("cache");
+        let stub = stub(200);
+        let client = Client::new(
+            Box::new(stub.clone()),
+            "http://aoc/",
+            Some("token".into()),
+            &dir,
+        )
+        .min_interval(Duration::ZERO);
+        let path = dir.join("2024/day01.txt");
+        assert_eq!(
+            client.fetch(2024, 1).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(
+            client.fetch(2024, 1).unwrap(),
+            Fetched::Cached(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n");
+
+        let requests = stub.requests.borrow();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "http://aoc/2024/day/1/input");
+        assert!(requests[0]
+            .headers
+            .contains(&("Cookie".to_string(), "session=token".to_string())));
+
+        // A cached input needs no token.
+        drop(requests);
+        let client = Client::new(Box::new(stub), "http://aoc", None, &dir);
+        assert_eq!(client.fetch(2024, 1).unwrap(), Fetched::Cached(path));
+        assert!(client.fetch(2024, 2).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let dir = temp_dir("errors");
+        for status in [404, 400, 502] {
+            let client = Client::new(Box::new(stub(status)), "http://aoc", Some("t".into()), &dir)
+                .min_interval(Duration::ZERO);
+            assert!(client.fetch(2024, 1).is_err(), "status {}", status);
+            assert!(!dir.join("2024/day01.txt").exists());
+        }
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let dir = temp_dir("rate");
+        let interval = Duration::from_millis(200);
+        let start = Instant::now();
+        for day in 1..=3 {
+            // A new client each time, as for separate runs of `aoc fetch`.
+            Client::new(Box::new(stub(200)), "http://aoc", Some("t".into()), &dir)
+                .min_interval(interval)
+                .fetch(2024, day)
+                .unwrap();
+        }
+        assert!(start.elapsed() >= 2 * interval);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let dir = temp_dir("submit");
+        let stub = Rc::new(Stub {
+            status: 200,
+            body: "<main><article><p>That's the right answer!</p></article></main>",
+            requests: RefCell::new(Vec::new()),
+        });
+        let client = Client::new(Box::new(stub.clone()), "http://aoc", Some("t".into()), &dir)
+            .min_interval(Duration::ZERO);
+        assert_eq!(
+            client.submit(2024, 3, Part::Two, "48").unwrap(),
+            Submitted::Verdict(Verdict::Correct, Duration::ZERO)
+        );
+        let request = &stub.requests.borrow()[0];
+        assert_eq!(
+            (request.method, request.url.as_str()),
+            ("POST", "http://aoc/2024/day/3/answer")
+        );
+        assert_eq!(request.body.as_deref(), Some("level=2&answer=48"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_submitted() {
+        let wrong = "That's not the right answer; your answer is too low. If you're stuck, \
+            make sure you're using the full input data. Please wait one minute before \
+            trying again. [Return to Day 1]";
+        assert_eq!(
+            Submitted::parse(wrong),
+            Some(Submitted::Verdict(Verdict::TooLow, Duration::from_secs(60)))
+        );
+        let wrong = "That's not the right answer. Please wait 5 minutes before trying again.";
+        assert_eq!(
+            Submitted::parse(wrong),
+            Some(Submitted::Verdict(Verdict::Wrong, Duration::from_secs(300)))
+        );
+        let too_soon = "You gave an answer too recently; you have to wait after submitting an \
+            answer before trying again.  You have 1m 23s left to wait. [Return to Day 1]";
+        assert_eq!(
+            Submitted::parse(too_soon),
+            Some(Submitted::TooSoon(Duration::from_secs(83)))
+        );
+        let solved = "You don't seem to be solving the right level.  Did you already complete it?";
+        assert_eq!(Submitted::parse(solved), Some(Submitted::AlreadySolved));
+        assert_eq!(Submitted::parse("<html>Log in</html>"), None);
+    }
+
+    #[test]
+    fn test_fetch_from_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let head = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect::<Vec<_>>();
+            let body = "3   4\n4   3\n";
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            head
+        });
+
+        let dir = temp_dir("server");
+        let client = Client::new(Box::new(TcpHttp), &base_url, Some("token".into()), &dir);
+        let path = dir.join("2024/day01.txt");
+        assert_eq!(
+            client.fetch(2024, 1).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(path).unwrap(), "3   4\n4   3\n");
+        let head = server.join().unwrap();
+        assert_eq!(head[0], "GET /2024/day/1/input HTTP/1.0");
+        assert!(head.contains(&"Cookie: session=token".to_string()));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,358 @@
+//! Parser combinators for puzzle inputs. A parser reads from a [`Cursor`],
+//! which remembers the whole input so that errors point at the right line
+//! and column, and small parsers combine into one that reads a day's input
+//! declaratively:
+//!
+//! ```
+//! use aoc::combinators::{lines, signed, Parser};
+//!
+//! let xy = || signed::<i64>().then_ignore(",").then(signed());
+//! let robot = "p=".ignore_then(xy()).then_ignore(" v=").then(xy());
+//! let robots = lines(robot).parse_all("p=0,4 v=3,-3\np=6,3 v=-1,-3\n").unwrap();
+//! assert_eq!(robots[1], ((6, 3), (-1, -3)));
+//! ```
+//!
+//! Days that split their input by hand use the helpers in [`crate::parse`]
+//! instead, which report errors the same way.
+
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// A position in the input: everything before it has been parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cursor<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(input: &'a str) -> Cursor<'a> {
+        Cursor { input, rest: input }
+    }
+
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// Skips `n` bytes, which must end on a character boundary.
+    pub fn advance(&self, n: usize) -> Cursor<'a> {
+        Cursor {
+            input: self.input,
+            rest: &self.rest[n..],
+        }
+    }
+
+    /// The cursor limited to the next `n` bytes, for parsing a line or block
+    /// on its own.
+    fn limit(&self, n: usize) -> Cursor<'a> {
+        Cursor {
+            input: self.input,
+            rest: &self.rest[..n],
+        }
+    }
+
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.input, self.rest, expected)
+    }
+}
+
+pub type Parsed<'a, T> = Result<(T, Cursor<'a>), ParseError>;
+
+pub trait Parser<'a> {
+    type Output;
+
+    fn parse(&self, cursor: Cursor<'a>) -> Parsed<'a, Self::Output>;
+
+    /// Parses all of `input` apart from trailing line breaks.
+    fn parse_all(&self, input: &'a str) -> Result<Self::Output, ParseError> {
+        let (value, rest) = self.parse(Cursor::new(input))?;
+        if !rest.rest.trim_end_matches(['\n', '\r']).is_empty() {
+            return Err(rest.error("end of input"));
+        }
+        Ok(value)
+    }
+
+    fn map<U>(self, f: impl Fn(Self::Output) -> U) -> impl Parser<'a, Output = U>
+    where
+        Self: Sized,
+    {
+        from_fn(move |cursor| {
+            let (value, cursor) = self.parse(cursor)?;
+            Ok((f(value), cursor))
+        })
+    }
+
+    /// Like [`Parser::map`] for conversions that can fail, with the error
+    /// saying what was expected at the start of this parser.
+    fn try_map<U, E: Into<String>>(
+        self,
+        f: impl Fn(Self::Output) -> Result<U, E>,
+    ) -> impl Parser<'a, Output = U>
+    where
+        Self: Sized,
+    {
+        from_fn(move |cursor| {
+            let (value, rest) = self.parse(cursor)?;
+            match f(value) {
+                Ok(value) => Ok((value, rest)),
+                Err(expected) => Err(cursor.error(expected)),
+            }
+        })
+    }
+
+    fn then<P: Parser<'a>>(self, next: P) -> impl Parser<'a, Output = (Self::Output, P::Output)>
+    where
+        Self: Sized,
+    {
+        from_fn(move |cursor| {
+            let (first, cursor) = self.parse(cursor)?;
+            let (second, cursor) = next.parse(cursor)?;
+            Ok(((first, second), cursor))
+        })
+    }
+
+    /// Parses `next` after this one, keeping only this one's output.
+    fn then_ignore<P: Parser<'a>>(self, next: P) -> impl Parser<'a, Output = Self::Output>
+    where
+        Self: Sized,
+    {
+        self.then(next).map(|(value, _)| value)
+    }
+
+    /// Parses `next` after this one, keeping only `next`'s output.
+    fn ignore_then<P: Parser<'a>>(self, next: P) -> impl Parser<'a, Output = P::Output>
+    where
+        Self: Sized,
+    {
+        self.then(next).map(|(_, value)| value)
+    }
+
+    /// Tries `other` from the same place if this one fails.
+    fn or<P: Parser<'a, Output = Self::Output>>(
+        self,
+        other: P,
+    ) -> impl Parser<'a, Output = Self::Output>
+    where
+        Self: Sized,
+    {
+        from_fn(move |cursor| self.parse(cursor).or_else(|_| other.parse(cursor)))
+    }
+
+    fn optional(self) -> impl Parser<'a, Output = Option<Self::Output>>
+    where
+        Self: Sized,
+    {
+        from_fn(move |cursor| match self.parse(cursor) {
+            Ok((value, cursor)) => Ok((Some(value), cursor)),
+            Err(_) => Ok((None, cursor)),
+        })
+    }
+}
+
+struct FromFn<F>(F);
+
+impl<'a, T, F: Fn(Cursor<'a>) -> Parsed<'a, T>> Parser<'a> for FromFn<F> {
+    type Output = T;
+
+    fn parse(&self, cursor: Cursor<'a>) -> Parsed<'a, T> {
+        (self.0)(cursor)
+    }
+}
+
+pub fn from_fn<'a, T>(f: impl Fn(Cursor<'a>) -> Parsed<'a, T>) -> impl Parser<'a, Output = T> {
+    FromFn(f)
+}
+
+/// A string parses itself literally.
+impl<'a> Parser<'a> for &str {
+    type Output = ();
+
+    fn parse(&self, cursor: Cursor<'a>) -> Parsed<'a, ()> {
+        if cursor.rest.starts_with(*self) {
+            Ok(((), cursor.advance(self.len())))
+        } else {
+            Err(cursor.error(format!("`{}`", self)))
+        }
+    }
+}
+
+/// The longest run of characters matching `f`, which may be empty.
+pub fn take_while<'a>(f: impl Fn(char) -> bool) -> impl Parser<'a, Output = &'a str> {
+    from_fn(move |cursor: Cursor<'a>| {
+        let end = cursor.rest.find(|c| !f(c)).unwrap_or(cursor.rest.len());
+        Ok((&cursor.rest[..end], cursor.advance(end)))
+    })
+}
+
+fn number<'a, T: FromStr>(sign: bool) -> impl Parser<'a, Output = T> {
+    from_fn(move |cursor: Cursor<'a>| {
+        let s = cursor.rest;
+        let start = usize::from(sign && s.starts_with(['-', '+']));
+        let end = start + s[start..].bytes().take_while(u8::is_ascii_digit).count();
+        match s[..end].parse() {
+            Ok(value) if end > start => Ok((value, cursor.advance(end))),
+            _ => Err(cursor.error("a number")),
+        }
+    })
+}
+
+/// Digits, as any integer type that fits them.
+pub fn unsigned<'a, T: FromStr>() -> impl Parser<'a, Output = T> {
+    number(false)
+}
+
+/// Digits after an optional `-` or `+`.
+pub fn signed<'a, T: FromStr>() -> impl Parser<'a, Output = T> {
+    number(true)
+}
+
+/// One or more `item`s with `separator` between them.
+pub fn separated<'a, P: Parser<'a>>(
+    item: P,
+    separator: impl Parser<'a>,
+) -> impl Parser<'a, Output = Vec<P::Output>> {
+    from_fn(move |cursor| {
+        let (first, mut cursor) = item.parse(cursor)?;
+        let mut items = vec![first];
+        while let Ok((_, next)) = separator.parse(cursor) {
+            let (value, next) = item.parse(next)?;
+            items.push(value);
+            cursor = next;
+        }
+        Ok((items, cursor))
+    })
+}
+
+/// `key=value`.
+pub fn field<'a, P: Parser<'a>>(
+    key: &'static str,
+    value: P,
+) -> impl Parser<'a, Output = P::Output> {
+    key.ignore_then("=").ignore_then(value)
+}
+
+/// A `\n` or `\r\n` line break.
+pub fn line_break<'a>() -> impl Parser<'a, Output = ()> {
+    "\n".or("\r\n")
+}
+
+fn line_len(s: &str) -> usize {
+    s.find('\n').map_or(s.len(), |i| i + 1)
+}
+
+/// Runs `p` on the first `len` bytes, which it must parse entirely apart
+/// from line breaks at the end.
+fn within<'a, P: Parser<'a>>(p: &P, cursor: Cursor<'a>, len: usize) -> Parsed<'a, P::Output> {
+    let (value, rest) = p.parse(cursor.limit(len))?;
+    if !rest.rest.trim_end_matches(['\n', '\r']).is_empty() {
+        return Err(rest.error("end of line"));
+    }
+    Ok((value, cursor.advance(len)))
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim_end_matches(['\n', '\r']).is_empty()
+}
+
+/// One or more lines, each parsed entirely by `line`, up to a blank line or
+/// the end of the input.
+pub fn lines<'a, P: Parser<'a>>(line: P) -> impl Parser<'a, Output = Vec<P::Output>> {
+    from_fn(move |mut cursor: Cursor<'a>| {
+        let mut values = Vec::new();
+        loop {
+            let len = line_len(cursor.rest);
+            if !values.is_empty() && (len == 0 || is_blank(&cursor.rest[..len])) {
+                return Ok((values, cursor));
+            }
+            let (value, next) = within(&line, cursor, len)?;
+            values.push(value);
+            cursor = next;
+        }
+    })
+}
+
+/// One or more blocks separated by blank lines, each parsed entirely by
+/// `block`. Blank lines before the first block are skipped.
+pub fn blocks<'a, P: Parser<'a>>(block: P) -> impl Parser<'a, Output = Vec<P::Output>> {
+    from_fn(move |mut cursor: Cursor<'a>| {
+        let mut values = Vec::new();
+        loop {
+            while let len @ 1.. = line_len(cursor.rest) {
+                if !is_blank(&cursor.rest[..len]) {
+                    break;
+                }
+                cursor = cursor.advance(len);
+            }
+            if cursor.is_empty() && !values.is_empty() {
+                return Ok((values, cursor));
+            }
+            let mut len = 0;
+            while let line @ 1.. = line_len(&cursor.rest[len..]) {
+                if is_blank(&cursor.rest[len..len + line]) {
+                    break;
+                }
+                len += line;
+            }
+            let (value, next) = within(&block, cursor, len)?;
+            values.push(value);
+            cursor = next;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blocks, field, line_break, lines, separated, signed, unsigned, Parser};
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(unsigned::<u8>().parse_all("42"), Ok(42));
+        assert_eq!(signed::<i64>().parse_all("-42"), Ok(-42));
+        assert_eq!(
+            unsigned::<u8>().parse_all("-4").unwrap_err().expected,
+            "a number"
+        );
+        assert_eq!(unsigned::<u8>().parse_all("300").unwrap_err().column, 1);
+        let error = unsigned::<u8>().parse_all("12x").unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (3, "end of input"));
+    }
+
+    #[test]
+    fn test_structure() {
+        let list = separated(unsigned::<u32>(), ",");
+        assert_eq!(list.parse_all("1,2,3\n"), Ok(vec![1, 2, 3]));
+
+        let register = "Register ".ignore_then(field("A", unsigned::<u32>()));
+        let program = "Program: ".ignore_then(separated(unsigned::<u8>(), ","));
+        let input = "\nRegister A=7\r\nRegister A=8\r\n\r\n\nProgram: 0,3\n";
+        let parser = blocks(
+            lines(register)
+                .map(|r| r.len())
+                .or(program.map(|p| p.len())),
+        );
+        assert_eq!(parser.parse_all(input), Ok(vec![2, 2]));
+
+        let error = lines(signed::<i8>()).parse_all("1\n2\nx\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        let error = lines(signed::<i8>()).parse_all("1\n2 \n").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.expected.as_str()),
+            (2, 2, "end of line")
+        );
+
+        let pair = unsigned::<u8>()
+            .then_ignore(line_break())
+            .then(unsigned::<u8>());
+        assert_eq!(pair.parse_all("1\r\n2"), Ok((1, 2)));
+        assert_eq!("x".optional().then("y").parse_all("y"), Ok((None, ())));
+    }
+}
@@ -0,0 +1,185 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::Path,
+};
+
+use crate::{
+    toml::{Document, Value},
+    Part, Solver,
+};
+
+/// A sample input next to the answers the puzzle text gives for it.
+///
+/// Every `<name>.txt` in an examples directory is paired with `<name>.toml`,
+/// which holds `part1 = ...` and `part2 = ...`. Either may be left out when
+/// the puzzle gives no answer for that part. A `[params]` table sets the
+/// solution's parameters for the example, such as a smaller grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Vec<(Part, String)>,
+    pub params: Vec<(String, String)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub example: String,
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} part {}: expected {}, got {}",
+            self.example,
+            self.part.number(),
+            self.expected,
+            self.actual
+        )
+    }
+}
+
+fn invalid(path: &Path, message: impl Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}: {}", path.display(), message),
+    )
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+type Expected = (Vec<(Part, String)>, Vec<(String, String)>);
+
+fn parse_expected(path: &Path, toml: &str) -> io::Result<Expected> {
+    let document = Document::parse(toml).map_err(|e| invalid(path, e))?;
+    let mut expected = Vec::new();
+    let mut params = Vec::new();
+    for (name, table) in document.tables() {
+        for (key, value) in table.iter() {
+            match (name, key) {
+                ("", "part1") => expected.push((Part::One, text(value))),
+                ("", "part2") => expected.push((Part::Two, text(value))),
+                ("params", _) => params.push((key.to_string(), text(value))),
+                _ => return Err(invalid(path, format!("unknown key `{}`", key))),
+            }
+        }
+    }
+    Ok((expected, params))
+}
+
+/// Every example in `dir`, sorted by name.
+pub fn load(dir: impl AsRef<Path>) -> io::Result<Vec<Example>> {
+    let dir = dir.as_ref();
+    let mut examples = Vec::new();
+    for entry in fs::read_dir(dir).map_err(|e| invalid(dir, e))? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|extension| extension != "txt") {
+            continue;
+        }
+        let expected_path = path.with_extension("toml");
+        let text = fs::read_to_string(&expected_path).map_err(|e| invalid(&expected_path, e))?;
+        let (expected, params) = parse_expected(&expected_path, &text)?;
+        examples.push(Example {
+            name: path.file_stem().unwrap().to_string_lossy().into_owned(),
+            input: fs::read_to_string(&path).map_err(|e| invalid(&path, e))?,
+            expected,
+            params,
+        });
+    }
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+/// Runs `solver` on the parts of `example` that have an expected answer,
+/// with the example's parameters. The solver's own parameters are restored
+/// afterwards.
+pub fn check(solver: &mut dyn Solver, example: &Example) -> Vec<Mismatch> {
+    let parts = example
+        .expected
+        .iter()
+        .map(|&(part, _)| part)
+        .collect::<Vec<_>>();
+    let saved = solver.params();
+    let set = example
+        .params
+        .iter()
+        .try_for_each(|(name, value)| solver.set_param(name, value));
+    let actual = match set {
+        Ok(()) => solver
+            .solve(&example.input, &parts)
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    };
+    solver.restore_params(&saved);
+    example
+        .expected
+        .iter()
+        .filter_map(|(part, expected)| {
+            let actual = match &actual {
+                Ok(answers) => answers
+                    .iter()
+                    .find(|(p, _)| p == part)
+                    .map(|(_, answer)| match answer {
+                        Ok(answer) => answer.to_string(),
+                        Err(e) => e.to_string(),
+                    })
+                    .unwrap_or_default(),
+                Err(e) => e.clone(),
+            };
+            (actual != *expected).then(|| Mismatch {
+                example: example.name.clone(),
+                part: *part,
+                expected: expected.clone(),
+                actual,
+            })
+        })
+        .collect()
+}
+
+/// Checks every example in `dir`, panicking with all mismatches at once.
+/// Meant to be called from a day's tests.
+pub fn assert_examples(solver: &mut dyn Solver, dir: impl AsRef<Path>) {
+    let examples = load(dir).unwrap_or_else(|e| panic!("{}", e));
+    assert!(!examples.is_empty(), "no examples for day {}", solver.day());
+    let day = solver.day();
+    let mismatches = examples
+        .iter()
+        .flat_map(|example| check(solver, example))
+        .map(|mismatch| format!("day {:02} {}", day, mismatch))
+        .collect::<Vec<_>>();
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::parse_expected;
+    use crate::Part;
+
+    #[test]
+    fn test_parse_expected() {
+        let text = "# From the puzzle text\npart1 = 143\npart2 = \"co,de\"\n\n[params]\nsize = 7\n";
+        assert_eq!(
+            parse_expected(Path::new("example.toml"), text).unwrap(),
+            (
+                vec![
+                    (Part::One, "143".to_string()),
+                    (Part::Two, "co,de".to_string())
+                ],
+                vec![("size".to_string(), "7".to_string())]
+            )
+        );
+        assert!(parse_expected(Path::new("example.toml"), "part3 = 1").is_err());
+    }
+}
@@ -0,0 +1,197 @@
+//! Checks that parsing and solving are total: whatever the input, a day
+//! answers or fails with an error, and never panics.
+//!
+//! [`fuzz`] is the entry point of the libFuzzer targets in `fuzz/`. Without
+//! that tooling, [`run`] mutates known inputs at random, which is what the
+//! days' tests and `aoc fuzz` use.
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
+
+use crate::{examples, generate::Rng, Part, Solver};
+
+/// Solves each part of `data` on its own, ignoring the outcome, so that
+/// only a panic is noticed.
+pub fn fuzz(solver: &dyn Solver, data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    for part in Part::BOTH {
+        let _ = solver.solve_part(input, part);
+    }
+}
+
+/// Characters that mean something in at least one day's input.
+const ALPHABET: &str = "0123456789-+,:=|^<>v.#@[]OSEXMASxyzpv \n\r\té";
+
+/// Numbers at the edges of the integer types.
+const NUMBERS: [&str; 9] = [
+    "0",
+    "1",
+    "-1",
+    "00",
+    "255",
+    "65536",
+    "4294967296",
+    "18446744073709551615",
+    "99999999999999999999999999999999999999999",
+];
+
+fn span(rng: &mut Rng, len: usize) -> (usize, usize) {
+    let start = rng.index(len + 1);
+    (start, (start + rng.range(1..=8) as usize).min(len))
+}
+
+/// `input` with a few random edits, such as deleting, duplicating or
+/// inserting characters, replacing a number or repeating a line.
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let alphabet = ALPHABET.chars().collect::<Vec<_>>();
+    let mut chars = input.chars().collect::<Vec<_>>();
+    for _ in 0..rng.range(1..=4) {
+        let len = chars.len();
+        match rng.below(7) {
+            0 => {
+                let (start, end) = span(rng, len);
+                chars.drain(start..end);
+            }
+            1 => {
+                let c = match chars.is_empty() || rng.chance(0.5) {
+                    true => *rng.choose(&alphabet),
+                    false => *rng.choose(&chars),
+                };
+                chars.insert(rng.index(len + 1), c);
+            }
+            2 if len > 0 => {
+                let index = rng.index(len);
+                chars[index] = *rng.choose(&alphabet);
+            }
+            3 => {
+                let (start, end) = span(rng, len);
+                let copy = chars[start..end].to_vec();
+                let at = rng.index(len + 1);
+                chars.splice(at..at, copy);
+            }
+            4 => {
+                let digits = (0..len)
+                    .filter(|&i| chars[i].is_ascii_digit())
+                    .collect::<Vec<_>>();
+                let Some(&start) = (!digits.is_empty()).then(|| rng.choose(&digits)) else {
+                    continue;
+                };
+                let end = (start..len)
+                    .find(|&i| !chars[i].is_ascii_digit())
+                    .unwrap_or(len);
+                chars.splice(start..end, rng.choose(&NUMBERS).chars());
+            }
+            5 => {
+                let mut lines = chars
+                    .split_inclusive(|&c| c == '\n')
+                    .map(<[char]>::to_vec)
+                    .collect::<Vec<_>>();
+                if lines.is_empty() {
+                    continue;
+                }
+                let line = rng.index(lines.len());
+                match rng.below(3) {
+                    0 => drop(lines.remove(line)),
+                    1 => lines.insert(line, lines[line].clone()),
+                    _ => {
+                        let other = rng.index(lines.len());
+                        lines.swap(line, other);
+                    }
+                }
+                chars = lines.concat();
+            }
+            _ => chars.truncate(rng.index(len + 1)),
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// An input that made a solver panic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Crash {
+    pub input: String,
+    pub message: String,
+}
+
+/// Fuzzes `solver` with `iterations` mutations of `seeds`, after the seeds
+/// themselves, stopping at the first panic.
+pub fn run(
+    solver: &dyn Solver,
+    seeds: &[String],
+    rng: &mut Rng,
+    iterations: usize,
+) -> Result<(), Crash> {
+    let mutated = (0..iterations).map(|_| {
+        let seed = rng.choose(seeds);
+        mutate(rng, seed)
+    });
+    for input in seeds.iter().cloned().chain(mutated) {
+        if let Err(payload) =
+            panic::catch_unwind(AssertUnwindSafe(|| fuzz(solver, input.as_bytes())))
+        {
+            let message = match payload.downcast::<String>() {
+                Ok(message) => *message,
+                Err(payload) => payload
+                    .downcast_ref::<&str>()
+                    .map_or("panicked".to_string(), |message| message.to_string()),
+            };
+            return Err(Crash { input, message });
+        }
+    }
+    Ok(())
+}
+
+/// Fuzzes `solver` from each example in `dir`, with that example's
+/// parameters, and panics with the first input that made it panic. Meant to
+/// be called from a day's tests.
+pub fn assert_total(solver: &mut dyn Solver, dir: impl AsRef<Path>, iterations: usize) {
+    let examples = examples::load(dir).unwrap_or_else(|e| panic!("{}", e));
+    let mut rng = Rng::new(0);
+    for example in &examples {
+        let saved = solver.params();
+        for (name, value) in &example.params {
+            solver.set_param(name, value).unwrap();
+        }
+        let result = run(
+            solver,
+            std::slice::from_ref(&example.input),
+            &mut rng,
+            iterations,
+        );
+        solver.restore_params(&saved);
+        if let Err(crash) = result {
+            panic!(
+                "day {:02} panicked on a mutation of example {}: {}\ninput: {:?}",
+                solver.day(),
+                example.name,
+                crash.message,
+                crash.input
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mutate;
+    use crate::generate::Rng;
+
+    #[test]
+    fn test_mutate() {
+        let mut rng = Rng::new(0);
+        let input = "12 34\n56 78\n";
+        let mutated = (0..100)
+            .map(|_| mutate(&mut rng, input))
+            .collect::<Vec<_>>();
+        assert!(mutated.iter().all(|m| m.len() < input.len() + 100));
+        assert!(mutated.iter().filter(|m| *m != input).count() > 90);
+        assert_eq!(
+            mutate(&mut Rng::new(3), input),
+            mutate(&mut Rng::new(3), input)
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Synthetic puzzle inputs of any size, for stress tests, property tests and
+//! scaling benchmarks where the one real input per day falls short.
+//!
+//! Generators are deterministic: the same seed and size give the same input
+//! on every platform. Where the construction fixes an answer, such as a
+//! planted clique or known wire swaps, the generator reports it so that the
+//! solution can be checked against it.
+
+use std::ops::RangeInclusive;
+
+use crate::{examples::Mismatch, Answer, Direction, Grid, Part, Position, Solver};
+
+/// A small SplitMix64 generator, good enough for puzzle inputs and stable
+/// across releases, unlike the standard library's hashers.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number below `n`, which must not be 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range");
+        start.wrapping_add(self.below(end.abs_diff(start) + 1) as i64)
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// A generated input with whatever answers are known from how it was built.
+#[derive(Clone, Debug)]
+pub struct Generated {
+    pub input: String,
+    pub known: Vec<(Part, Answer)>,
+}
+
+impl Generated {
+    pub fn new(input: impl Into<String>) -> Generated {
+        Generated {
+            input: input.into(),
+            known: Vec::new(),
+        }
+    }
+
+    pub fn known(mut self, part: Part, answer: impl Into<Answer>) -> Generated {
+        self.known.push((part, answer.into()));
+        self
+    }
+}
+
+/// A maze of `#` walls and `.` passages with exactly one way between any two
+/// cells at odd coordinates, `width` by `height` cells rounded up to odd
+/// sizes of at least 3.
+pub fn maze(rng: &mut Rng, width: usize, height: usize) -> Grid<u8> {
+    let (width, height) = ((width.max(3) | 1) as i64, (height.max(3) | 1) as i64);
+    let mut maze = Grid::filled(width, height, b'#');
+    let start = Position::new(1, 1);
+    maze[start] = b'.';
+    let mut stack = vec![start];
+    while let Some(&cell) = stack.last() {
+        let unvisited = Direction::CARDINAL
+            .into_iter()
+            .filter(|&direction| maze.get(cell + direction.delta() * 2) == Some(&b'#'))
+            .collect::<Vec<_>>();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let direction = *rng.choose(&unvisited);
+        let next = cell + direction.delta() * 2;
+        maze[cell + direction] = b'.';
+        maze[next] = b'.';
+        stack.push(next);
+    }
+    maze
+}
+
+/// Solves `parts` of `generated` and compares them with the known answers.
+/// Parts without a known answer only have to be solved without panicking.
+pub fn check(
+    solver: &dyn Solver,
+    name: &str,
+    generated: &Generated,
+    parts: &[Part],
+) -> Vec<Mismatch> {
+    let mismatch = |part, expected: String, actual: String| Mismatch {
+        example: name.to_string(),
+        part,
+        expected,
+        actual,
+    };
+    let answers = match solver.solve(&generated.input, parts) {
+        Ok(answers) => answers,
+        Err(e) => {
+            return parts
+                .iter()
+                .map(|&part| mismatch(part, "an answer".to_string(), e.to_string()))
+                .collect()
+        }
+    };
+    answers
+        .into_iter()
+        .filter_map(|(part, actual)| {
+            let actual = match actual {
+                Ok(actual) => actual.to_string(),
+                Err(e) => return Some(mismatch(part, "an answer".to_string(), e.to_string())),
+            };
+            let (_, expected) = generated.known.iter().find(|(p, _)| *p == part)?;
+            let expected = expected.to_string();
+            (expected != actual).then(|| mismatch(part, expected, actual))
+        })
+        .collect()
+}
+
+/// Generates inputs of every size in `sizes` from a few seeds and checks
+/// `parts` of each, panicking with all mismatches at once. Meant to be
+/// called from a day's tests.
+pub fn assert_generated(solver: &dyn Solver, parts: &[Part], sizes: &[usize]) {
+    let day = solver.day();
+    let mut mismatches = Vec::new();
+    for seed in 0..3 {
+        for &size in sizes {
+            let generated = solver
+                .generate(seed, size)
+                .unwrap_or_else(|| panic!("day {} has no generator", day));
+            let name = format!("seed {} size {}", seed, size);
+            mismatches.extend(
+                check(solver, &name, &generated, parts)
+                    .into_iter()
+                    .map(|mismatch| format!("day {:02} {}", day, mismatch)),
+            );
+        }
+    }
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(0);
+        assert_eq!(rng.next_u64(), 0xe220a8397b1dcdaf);
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3..=3)));
+            assert!(rng.below(5) < 5);
+        }
+        let mut items = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+        assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+    }
+}
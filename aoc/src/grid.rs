@@ -0,0 +1,275 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use crate::{parse, Direction, ParseError, Position};
+
+/// Which cells count as adjacent to a position.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Neighbourhood {
+    /// The four orthogonal neighbours.
+    VonNeumann,
+    /// The eight orthogonal and diagonal neighbours.
+    Moore,
+    /// Any set of offsets from the position.
+    Offsets(Vec<Position>),
+}
+
+impl Neighbourhood {
+    /// Every cell within Manhattan distance `radius`, except the position
+    /// itself.
+    pub fn manhattan(radius: i64) -> Neighbourhood {
+        let offsets = (-radius..=radius)
+            .flat_map(|y| {
+                let width = radius - y.abs();
+                (-width..=width).map(move |x| Position { x, y })
+            })
+            .filter(|&offset| offset != Position::ORIGIN)
+            .collect();
+        Neighbourhood::Offsets(offsets)
+    }
+
+    pub fn offsets(&self) -> &[Position] {
+        const VON_NEUMANN: [Position; 4] = [
+            Direction::East.delta(),
+            Direction::North.delta(),
+            Direction::West.delta(),
+            Direction::South.delta(),
+        ];
+        const MOORE: [Position; 8] = [
+            Direction::East.delta(),
+            Direction::North.delta(),
+            Direction::West.delta(),
+            Direction::South.delta(),
+            Direction::NorthEast.delta(),
+            Direction::NorthWest.delta(),
+            Direction::SouthWest.delta(),
+            Direction::SouthEast.delta(),
+        ];
+        match self {
+            Neighbourhood::VonNeumann => &VON_NEUMANN,
+            Neighbourhood::Moore => &MOORE,
+            Neighbourhood::Offsets(offsets) => offsets,
+        }
+    }
+
+    /// The neighbouring positions, without any bounds check.
+    pub fn around(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offsets().iter().map(move |&offset| position + offset)
+    }
+}
+
+/// A dense rectangular grid indexed by [`Position`], with `x` growing to the
+/// east and `y` growing to the south. `Grid` on its own only tracks bounds.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T = ()> {
+    width: i64,
+    height: i64,
+    cells: Vec<T>,
+}
+
+impl Grid {
+    pub fn new(width: i64, height: i64) -> Grid {
+        Grid::filled(width, height, ())
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a rectangular byte map, detecting its dimensions from the first
+    /// line. Surrounding blank lines and `\r\n` line endings are ignored.
+    pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse_block(input, input)
+    }
+
+    /// Like [`Grid::parse`] for a map that is only part of `input`, such as
+    /// the first of several blank line separated blocks.
+    pub fn parse_block(input: &str, block: &str) -> Result<Grid<u8>, ParseError> {
+        let mut lines = parse::lines(block).peekable();
+        let width = match lines.peek() {
+            Some(line) if !line.is_empty() => line.len(),
+            _ => return Err(ParseError::new(input, block, "a grid")),
+        };
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in lines {
+            if line.len() != width {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!("a row of {} cells", width),
+                ));
+            }
+            cells.extend_from_slice(line.as_bytes());
+            height += 1;
+        }
+        Ok(Grid {
+            width: width as i64,
+            height,
+            cells,
+        })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: i64, height: i64, value: T) -> Grid<T> {
+        let len = (width.max(0) * height.max(0)) as usize;
+        Grid {
+            width,
+            height,
+            cells: vec![value; len],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> i64 {
+        self.width
+    }
+
+    pub fn height(&self) -> i64 {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        (0..self.width).contains(&position.x) && (0..self.height).contains(&position.y)
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| (position.x + position.y * self.width) as usize)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// All positions in reading order, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1) as usize)
+    }
+
+    pub fn find(&self, value: T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| **cell == value)
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn neighbours(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = (Direction, Position)> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .map(move |direction| (direction, position + direction))
+            .filter(|&(_, position)| self.contains(position))
+    }
+
+    /// Like [`Grid::neighbours`], including the four diagonals.
+    pub fn moore_neighbours(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = (Direction, Position)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| (direction, position + direction))
+            .filter(|&(_, position)| self.contains(position))
+    }
+
+    /// The neighbours of `position` inside the grid.
+    pub fn neighbourhood<'a>(
+        &'a self,
+        position: Position,
+        neighbourhood: &'a Neighbourhood,
+    ) -> impl Iterator<Item = Position> + 'a {
+        neighbourhood
+            .around(position)
+            .filter(|&position| self.contains(position))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.index_of(position) {
+            Some(index) => &self.cells[index],
+            None => panic!("{:?} is outside the grid", position),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        match self.index_of(position) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("{:?} is outside the grid", position),
+        }
+    }
+}
+
+impl Display for Grid<u8> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, Neighbourhood};
+    use crate::{Direction, Position};
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("\r\n#.\r\n.^\r\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.find(b'^'), Some(Position { x: 1, y: 1 }));
+        assert_eq!(grid.get(Position { x: 2, y: 0 }), None);
+        assert_eq!(grid.to_string(), "#.\n.^\n");
+
+        let error = Grid::parse("#.\n#\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_neighbourhoods() {
+        let grid = Grid::new(3, 3);
+        let corner = Position { x: 0, y: 0 };
+        assert_eq!(grid.neighbours(corner).count(), 2);
+        assert_eq!(
+            grid.moore_neighbours(corner).map(|(d, _)| d).last(),
+            Some(Direction::SouthEast)
+        );
+        assert_eq!(Neighbourhood::manhattan(2).offsets().len(), 12);
+        let centre = Position { x: 1, y: 1 };
+        let diamond = Neighbourhood::manhattan(2);
+        assert_eq!(grid.neighbourhood(centre, &diamond).count(), 8);
+    }
+}
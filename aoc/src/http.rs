@@ -0,0 +1,245 @@
+//! Just enough HTTP to talk to adventofcode.com without any dependencies.
+//! [`Http`] is the backend: [`TcpHttp`] speaks plain HTTP over a socket,
+//! which is what a local stub server needs, and [`CurlHttp`] hands HTTPS to
+//! the `curl` binary.
+
+use std::{
+    io::{self, Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub method: &'static str,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+impl Request {
+    pub fn get(url: impl Into<String>) -> Request {
+        Request {
+            method: "GET",
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// A form POST of `fields`.
+    pub fn post_form(url: impl Into<String>, fields: &[(&str, &str)]) -> Request {
+        let body = fields
+            .iter()
+            .map(|(name, value)| format!("{}={}", form_encode(name), form_encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+        Request {
+            method: "POST",
+            url: url.into(),
+            headers: vec![(
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            )],
+            body: Some(body),
+        }
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Request {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+fn form_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            b' ' => "+".to_string(),
+            b => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+pub trait Http {
+    fn send(&self, request: &Request) -> io::Result<Response>;
+}
+
+/// The backend for `url`: [`TcpHttp`] for `http://` and [`CurlHttp`] for
+/// anything else.
+pub fn backend(url: &str) -> Box<dyn Http> {
+    match url.starts_with("http://") {
+        true => Box::new(TcpHttp),
+        false => Box::new(CurlHttp),
+    }
+}
+
+fn invalid(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+/// Plain HTTP/1.0, so that the server closes the connection after a body
+/// that is never chunked.
+pub struct TcpHttp;
+
+impl Http for TcpHttp {
+    fn send(&self, request: &Request) -> io::Result<Response> {
+        let rest = request
+            .url
+            .strip_prefix("http://")
+            .ok_or_else(|| invalid(format!("not an http:// URL: {}", request.url)))?;
+        let (host, path) = match rest.find('/') {
+            Some(index) => rest.split_at(index),
+            None => (rest, "/"),
+        };
+        let address = match host.contains(':') {
+            true => host.to_string(),
+            false => format!("{}:80", host),
+        };
+        let mut stream = TcpStream::connect(address)?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+
+        let mut head = format!("{} {} HTTP/1.0\r\nHost: {}\r\n", request.method, path, host);
+        for (name, value) in &request.headers {
+            head += &format!("{}: {}\r\n", name, value);
+        }
+        let body = request.body.as_deref().unwrap_or_default();
+        if request.body.is_some() {
+            head += &format!("Content-Length: {}\r\n", body.len());
+        }
+        head += "\r\n";
+        stream.write_all(head.as_bytes())?;
+        stream.write_all(body.as_bytes())?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| invalid("a response without a blank line after the headers"))?;
+        let status = head
+            .split(' ')
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(|| invalid(format!("a response without a status: {:?}", head)))?;
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// A string in a curl config file, quoted and escaped.
+fn curl_string(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted += "\\n",
+            '\r' => quoted += "\\r",
+            '\t' => quoted += "\\t",
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// The headers and body of `request` as a curl config file, which curl
+/// reads from its stdin so that the session cookie stays out of the command
+/// line that other users can see.
+fn curl_config(request: &Request) -> String {
+    let mut config = String::new();
+    for (name, value) in &request.headers {
+        config += &format!(
+            "header = {}\n",
+            curl_string(&format!("{}: {}", name, value))
+        );
+    }
+    // Raw, as curl would read a file named by a body starting with `@`.
+    if let Some(body) = &request.body {
+        config += &format!("data-raw = {}\n", curl_string(body));
+    }
+    config
+}
+
+/// Runs `curl`, which has to be on the `PATH`.
+pub struct CurlHttp;
+
+impl Http for CurlHttp {
+    fn send(&self, request: &Request) -> io::Result<Response> {
+        let mut command = Command::new("curl");
+        command
+            .args(["--silent", "--show-error", "--max-time", "30"])
+            .args(["--request", request.method])
+            // The status goes on a line of its own after the body.
+            .args(["--write-out", "\n%{http_code}"])
+            .args(["--config", "-"])
+            .arg(&request.url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let mut child = command
+            .spawn()
+            .map_err(|e| io::Error::new(e.kind(), format!("curl: {}", e)))?;
+        // Dropped once written, so that curl sees the end of the config.
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(curl_config(request).as_bytes())?;
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            let message = String::from_utf8_lossy(&output.stderr);
+            return Err(io::Error::other(format!("curl: {}", message.trim())));
+        }
+        let output = String::from_utf8(output.stdout).map_err(|e| invalid(e.to_string()))?;
+        let (body, status) = output
+            .rsplit_once('\n')
+            .ok_or_else(|| invalid("curl printed no status"))?;
+        let status = status
+            .parse()
+            .map_err(|_| invalid(format!("curl printed no status: {:?}", status)))?;
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{curl_config, Request};
+
+    #[test]
+    fn test_post_form() {
+        let request = Request::post_form("http://aoc", &[("level", "1"), ("answer", "a,b c&d")]);
+        assert_eq!(request.body.unwrap(), "level=1&answer=a%2Cb+c%26d");
+    }
+
+    #[test]
+    fn test_curl_config() {
+        let request = Request::post_form("https://aoc", &[("answer", "1")])
+            .header("Cookie", "session=\"a\\b\"");
+        assert_eq!(
+            curl_config(&request),
+            "header = \"Content-Type: application/x-www-form-urlencoded\"\n\
+            header = \"Cookie: session=\\\"a\\\\b\\\"\"\n\
+            data-raw = \"answer=1\"\n"
+        );
+    }
+}
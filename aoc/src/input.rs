@@ -0,0 +1,47 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+pub const INPUT_DIR: &str = "inputs";
+
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// A path argument, where `-` means stdin, falling back to the
+    /// conventional `inputs/YYYY/dayNN.txt` when there is none.
+    pub fn from_arg(arg: Option<&str>, year: u16, day: u8) -> Source {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(arg) => Source::Path(arg.into()),
+            None => Source::Path(default_path(year, day)),
+        }
+    }
+}
+
+/// Where the input of `day` of `year` lives under `dir`.
+pub fn path_in(dir: impl AsRef<Path>, year: u16, day: u8) -> PathBuf {
+    dir.as_ref()
+        .join(year.to_string())
+        .join(format!("day{:02}.txt", day))
+}
+
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    path_in(INPUT_DIR, year, day)
+}
+
+pub fn read(source: &Source) -> io::Result<String> {
+    match source {
+        Source::Path(path) => fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e))),
+        Source::Stdin => {
+            let mut buf = String::new();
+            io::stdin().read_to_string(&mut buf)?;
+            Ok(buf)
+        }
+    }
+}
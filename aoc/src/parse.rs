@@ -0,0 +1,217 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error at `at`, which must be a subslice of `input` so that the line
+    /// and column can be recovered from its position.
+    pub fn new(input: &str, at: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(0);
+        ParseError::at_offset(input, offset, expected)
+    }
+
+    pub fn at_offset(input: &str, offset: usize, expected: impl Into<String>) -> ParseError {
+        let offset = (0..=offset.min(input.len()))
+            .rev()
+            .find(|&i| input.is_char_boundary(i))
+            .unwrap_or(0);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let rest = &input[offset..];
+        let text = &rest[..rest.find(['\n', '\r']).unwrap_or(rest.len())];
+        ParseError {
+            day: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.chars().take(40).collect(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn with_day(self, day: u8) -> ParseError {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        match self.text.as_str() {
+            "" => f.write_str("found end of line"),
+            text => write!(f, "found `{}`", text),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn split_once<'a>(
+    input: &str,
+    s: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(input, &s[s.len()..], format!("`{}`", delimiter)))
+}
+
+pub fn strip_prefix<'a>(input: &str, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(input, s, format!("`{}`", prefix)))
+}
+
+pub fn number<T: FromStr>(input: &str, s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(input, s, "a number"))
+}
+
+/// Fails at the first character that is neither a `\n` or `\r\n` line
+/// break nor one of `allowed`.
+pub fn charset(input: &str, allowed: &str) -> Result<(), ParseError> {
+    let is_line_break =
+        |index: usize, c| c == '\n' || (c == '\r' && input[index..].starts_with("\r\n"));
+    let invalid = input
+        .char_indices()
+        .find(|&(index, c)| !allowed.contains(c) && !is_line_break(index, c));
+    match invalid.map(|(index, _)| index) {
+        Some(index) => Err(ParseError::at_offset(
+            input,
+            index,
+            format!("one of `{}`", allowed),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Fails unless `tile` appears in `block` exactly once.
+pub fn unique(input: &str, block: &str, tile: char) -> Result<(), ParseError> {
+    let mut found = block.match_indices(tile).map(|(index, _)| &block[index..]);
+    match (found.next(), found.next()) {
+        (None, _) => Err(ParseError::new(
+            input,
+            &block[block.len()..],
+            format!("`{}`", tile),
+        )),
+        (Some(_), Some(second)) => Err(ParseError::new(
+            input,
+            second,
+            format!("only one `{}`", tile),
+        )),
+        (Some(_), None) => Ok(()),
+    }
+}
+
+/// Fails at the first cell on the edge of the map in `block` that is not
+/// `wall`, for maps whose solutions never look past the walls.
+pub fn walled(input: &str, block: &str, wall: char) -> Result<(), ParseError> {
+    let rows = lines(block).collect::<Vec<_>>();
+    for (row, line) in rows.iter().enumerate() {
+        let edge = row == 0 || row + 1 == rows.len();
+        let gap = line.char_indices().find(|&(index, c)| {
+            c != wall && (edge || index == 0 || index + c.len_utf8() == line.len())
+        });
+        if let Some((index, _)) = gap {
+            return Err(ParseError::new(
+                input,
+                &line[index..],
+                format!("`{}` around the map", wall),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The lines of `input` with surrounding blank lines removed.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.trim_matches(['\n', '\r']).lines()
+}
+
+/// Splits on blank lines, tolerating `\r\n` line endings.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input.trim_matches(['\n', '\r']);
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let mut lines = rest.split_inclusive('\n');
+        let mut end = 0;
+        for line in lines.by_ref() {
+            if line.trim_end_matches(['\n', '\r']).is_empty() {
+                break;
+            }
+            end += line.len();
+        }
+        let block = rest[..end].trim_end_matches(['\n', '\r']);
+        rest = rest[end..].trim_start_matches(['\n', '\r']);
+        Some(block)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blocks, charset, number, split_once, unique, walled, ParseError};
+
+    #[test]
+    fn test_position() {
+        let input = "ab\ncdé f\n";
+        let error = ParseError::new(input, &input[8..], "`g`").with_day(4);
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.text, "f");
+        assert_eq!(
+            error.to_string(),
+            "day 4, line 2, column 5: expected `g`, found `f`"
+        );
+    }
+
+    #[test]
+    fn test_helpers() {
+        let input = "x: 12\ny 3";
+        let line = &input[6..];
+        let error = split_once(input, line, ": ").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.text, "");
+        assert_eq!(number::<u8>(input, &input[3..5]), Ok(12));
+        assert_eq!(number::<u8>(input, &input[7..]).unwrap_err().column, 2);
+        assert!(charset("1\r\n2\n", "12").is_ok());
+        assert_eq!(charset("1\r2", "12").unwrap_err().column, 2);
+    }
+
+    #[test]
+    fn test_maps() {
+        let map = "####\n#@.#\n####\n";
+        assert!(unique(map, map, '@').is_ok() && walled(map, map, '#').is_ok());
+        let error = unique(map, map, 'E').unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (4, "`E`"));
+        let map = "####\n#@@.\n####\n";
+        assert_eq!(unique(map, map, '@').unwrap_err().column, 3);
+        assert_eq!(walled(map, map, '#').unwrap_err().column, 4);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "a\r\nb\r\n\r\nc\n\n\nd\n";
+        assert_eq!(blocks(input).collect::<Vec<_>>(), ["a\r\nb", "c", "d"]);
+    }
+}
@@ -0,0 +1,224 @@
+use std::{
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug, Default)]
+pub struct Position {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Position {
+    pub const ORIGIN: Position = Position { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Position {
+        Position { x, y }
+    }
+}
+
+impl Add for Position {
+    type Output = Position;
+
+    fn add(self, other: Position) -> Position {
+        Position::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Position {
+    fn add_assign(&mut self, other: Position) {
+        *self = *self + other
+    }
+}
+
+impl Sub for Position {
+    type Output = Position;
+
+    fn sub(self, other: Position) -> Position {
+        Position::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Position {
+    fn sub_assign(&mut self, other: Position) {
+        *self = *self - other
+    }
+}
+
+impl Neg for Position {
+    type Output = Position;
+
+    fn neg(self) -> Position {
+        Position::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Position {
+    type Output = Position;
+
+    fn mul(self, scalar: i64) -> Position {
+        Position::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl Add<Direction> for Position {
+    type Output = Position;
+
+    fn add(self, direction: Direction) -> Position {
+        self + direction.delta()
+    }
+}
+
+/// Compass directions on a grid where `y` grows to the south, so north is
+/// `-y`.
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+pub enum Direction {
+    East,
+    North,
+    West,
+    South,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+}
+
+impl Direction {
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::East,
+        Direction::North,
+        Direction::West,
+        Direction::South,
+    ];
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::NorthEast,
+        Direction::NorthWest,
+        Direction::SouthWest,
+        Direction::SouthEast,
+    ];
+    pub const ALL: [Direction; 8] = [
+        Direction::East,
+        Direction::North,
+        Direction::West,
+        Direction::South,
+        Direction::NorthEast,
+        Direction::NorthWest,
+        Direction::SouthWest,
+        Direction::SouthEast,
+    ];
+
+    pub const fn delta(self) -> Position {
+        match self {
+            Direction::East => Position::new(1, 0),
+            Direction::North => Position::new(0, -1),
+            Direction::West => Position::new(-1, 0),
+            Direction::South => Position::new(0, 1),
+            Direction::NorthEast => Position::new(1, -1),
+            Direction::NorthWest => Position::new(-1, -1),
+            Direction::SouthWest => Position::new(-1, 1),
+            Direction::SouthEast => Position::new(1, 1),
+        }
+    }
+
+    pub fn from_delta(delta: Position) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.delta() == delta)
+    }
+
+    /// A quarter turn counterclockwise.
+    pub const fn turn_left(self) -> Direction {
+        match self {
+            Direction::East => Direction::North,
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::NorthEast => Direction::NorthWest,
+            Direction::NorthWest => Direction::SouthWest,
+            Direction::SouthWest => Direction::SouthEast,
+            Direction::SouthEast => Direction::NorthEast,
+        }
+    }
+
+    /// A quarter turn clockwise.
+    pub const fn turn_right(self) -> Direction {
+        self.opposite().turn_left()
+    }
+
+    pub const fn opposite(self) -> Direction {
+        self.turn_left().turn_left()
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        Direction::DIAGONAL.contains(&self)
+    }
+
+    /// Reads an arrow `^>v<` or a compass letter `NESW`.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '>' | 'E' => Some(Direction::East),
+            '^' | 'N' => Some(Direction::North),
+            '<' | 'W' => Some(Direction::West),
+            'v' | 'S' => Some(Direction::South),
+            _ => None,
+        }
+    }
+}
+
+/// Accepts what [`Direction::from_char`] does, plus the diagonals `NE`, `NW`,
+/// `SW` and `SE`.
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Direction, String> {
+        match s {
+            "NE" => Ok(Direction::NorthEast),
+            "NW" => Ok(Direction::NorthWest),
+            "SW" => Ok(Direction::SouthWest),
+            "SE" => Ok(Direction::SouthEast),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next().and_then(Direction::from_char), chars.next()) {
+                    (Some(direction), None) => Ok(direction),
+                    _ => Err(format!("unknown direction `{}`", s)),
+                }
+            }
+        }
+    }
+}
+
+pub fn tc_dist(pos1: Position, pos2: Position) -> i64 {
+    (pos1.x - pos2.x).abs() + (pos1.y - pos2.y).abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Position};
+
+    #[test]
+    fn test_algebra() {
+        let p = Position::new(3, -2);
+        assert_eq!(
+            p + Position::new(1, 1) - Position::new(4, 4),
+            Position::new(0, -5)
+        );
+        assert_eq!(p * 2, Position::new(6, -4));
+        assert_eq!(p + Direction::NorthEast, Position::new(4, -3));
+    }
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.opposite().delta(), -direction.delta());
+            assert_eq!(
+                direction.turn_right().is_diagonal(),
+                direction.is_diagonal()
+            );
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!("v".parse(), Ok(Direction::South));
+        assert_eq!("SW".parse(), Ok(Direction::SouthWest));
+        assert!("x".parse::<Direction>().is_err());
+    }
+}
@@ -0,0 +1,305 @@
+//! Drawing grids to the terminal with ANSI colours and to PPM or PNG images,
+//! with layers for paths, visited sets and highlighted cells on top.
+
+use std::{
+    fmt::{self, Display, Formatter, Write},
+    fs, io,
+    path::Path,
+};
+
+use crate::{Grid, Position};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Colour {
+    pub const BLACK: Colour = Colour::new(0, 0, 0);
+    pub const DARK_GREY: Colour = Colour::new(64, 64, 64);
+    pub const GREY: Colour = Colour::new(160, 160, 160);
+    pub const WHITE: Colour = Colour::new(255, 255, 255);
+    pub const RED: Colour = Colour::new(230, 60, 60);
+    pub const GREEN: Colour = Colour::new(60, 200, 90);
+    pub const BLUE: Colour = Colour::new(70, 120, 230);
+    pub const YELLOW: Colour = Colour::new(240, 210, 60);
+    pub const CYAN: Colour = Colour::new(60, 200, 220);
+    pub const MAGENTA: Colour = Colour::new(210, 80, 210);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Colour {
+        Colour { r, g, b }
+    }
+}
+
+/// What one grid cell looks like: a glyph for the terminal and a colour used
+/// for the glyph there and for the whole cell in images.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub glyph: char,
+    pub colour: Colour,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, colour: Colour) -> Cell {
+        Cell { glyph, colour }
+    }
+}
+
+/// Cells drawn over the grid in one colour, keeping the glyphs underneath
+/// unless the layer has its own.
+#[derive(Clone, Debug)]
+pub struct Layer {
+    positions: Vec<Position>,
+    colour: Colour,
+    glyph: Option<char>,
+}
+
+impl Layer {
+    pub fn new(positions: impl IntoIterator<Item = Position>, colour: Colour) -> Layer {
+        Layer {
+            positions: positions.into_iter().collect(),
+            colour,
+            glyph: None,
+        }
+    }
+
+    pub fn glyph(mut self, glyph: char) -> Layer {
+        self.glyph = Some(glyph);
+        self
+    }
+}
+
+/// A grid ready to draw. Layers are applied in the order they are added, so
+/// later layers cover earlier ones, and positions outside the grid are
+/// ignored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Render {
+    cells: Grid<Cell>,
+}
+
+/// The usual puzzle map colours: walls grey, open floor dark and everything
+/// else, such as the start and end, white.
+pub fn map_cell(&b: &u8) -> Cell {
+    match b {
+        b'#' => Cell::new('#', Colour::GREY),
+        b'.' => Cell::new('.', Colour::DARK_GREY),
+        b => Cell::new(b as char, Colour::WHITE),
+    }
+}
+
+impl Render {
+    pub fn new<T>(grid: &Grid<T>, cell: impl FnMut(&T) -> Cell) -> Render {
+        Render {
+            cells: grid.map(cell),
+        }
+    }
+
+    /// Renders a byte map with [`map_cell`].
+    pub fn map(grid: &Grid<u8>) -> Render {
+        Render::new(grid, map_cell)
+    }
+
+    pub fn layer(mut self, layer: Layer) -> Render {
+        for position in layer.positions {
+            if let Some(cell) = self.cells.get_mut(position) {
+                cell.colour = layer.colour;
+                cell.glyph = layer.glyph.unwrap_or(cell.glyph);
+            }
+        }
+        self
+    }
+
+    pub fn width(&self) -> i64 {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> i64 {
+        self.cells.height()
+    }
+
+    pub(crate) fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.cells.rows()
+    }
+
+    /// The part of the grid `width` by `height` cells from `origin`, cut down
+    /// to what the grid covers.
+    pub fn crop(&self, origin: Position, width: i64, height: i64) -> Render {
+        let x = origin.x.clamp(0, self.width());
+        let y = origin.y.clamp(0, self.height());
+        let width = ((origin.x + width).clamp(0, self.width()) - x).max(0);
+        let height = ((origin.y + height).clamp(0, self.height()) - y).max(0);
+        let mut cells = Grid::filled(width, height, Cell::new(' ', Colour::BLACK));
+        for position in cells.positions() {
+            cells[position] = self.cells[position + Position::new(x, y)];
+        }
+        Render { cells }
+    }
+
+    /// The grid as lines of 24-bit ANSI coloured glyphs.
+    pub fn ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.cells.rows() {
+            let mut current = None;
+            for cell in row {
+                if current != Some(cell.colour) {
+                    let Colour { r, g, b } = cell.colour;
+                    let _ = write!(out, "\x1b[38;2;{};{};{}m", r, g, b);
+                    current = Some(cell.colour);
+                }
+                out.push(cell.glyph);
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        out
+    }
+
+    /// The RGB pixels, `scale` by `scale` per cell, row by row.
+    fn pixels(&self, scale: usize) -> impl Iterator<Item = Vec<u8>> + '_ {
+        self.cells.rows().flat_map(move |row| {
+            let line = row
+                .iter()
+                .flat_map(|cell| [cell.colour.r, cell.colour.g, cell.colour.b].repeat(scale))
+                .collect::<Vec<_>>();
+            std::iter::repeat_n(line, scale)
+        })
+    }
+
+    fn image_size(&self, scale: usize) -> (usize, usize) {
+        (
+            self.width().max(0) as usize * scale,
+            self.height().max(0) as usize * scale,
+        )
+    }
+
+    /// A binary PPM image with `scale` pixels per cell side.
+    pub fn ppm(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = self.image_size(scale);
+        let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        self.pixels(scale).for_each(|line| out.extend(line));
+        out
+    }
+
+    /// An uncompressed PNG image with `scale` pixels per cell side.
+    pub fn png(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = self.image_size(scale);
+        let mut header = Vec::new();
+        header.extend((width as u32).to_be_bytes());
+        header.extend((height as u32).to_be_bytes());
+        // 8 bit RGB, default compression and filtering, no interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+        let mut raw = Vec::new();
+        for line in self.pixels(scale) {
+            raw.push(0);
+            raw.extend(line);
+        }
+
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut out, b"IHDR", &header);
+        png_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    /// Writes `-` to stdout in colour, and paths ending in `.ppm` or `.png`
+    /// as images.
+    pub fn save(&self, path: impl AsRef<Path>, scale: usize) -> io::Result<()> {
+        let path = path.as_ref();
+        if path == Path::new("-") {
+            print!("{}", self.ansi());
+            return Ok(());
+        }
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.ppm(scale),
+            Some("png") => self.png(scale),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "expected a .ppm or .png path",
+                ))
+            }
+        };
+        fs::write(path, bytes)
+    }
+}
+
+/// The glyphs without colours.
+impl Display for Render {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.cells.rows() {
+            for cell in row {
+                f.write_char(cell.glyph)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, &b| {
+        (0..8).fold(crc ^ b as u32, |crc, _| {
+            (crc >> 1) ^ (0xedb88320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// A zlib stream of stored deflate blocks, which every PNG reader accepts and
+/// which needs no compressor.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(u8::from(blocks.peek().is_none()));
+        out.extend((block.len() as u16).to_le_bytes());
+        out.extend((!(block.len() as u16)).to_le_bytes());
+        out.extend(block);
+    }
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    out.extend(((b << 16) | a).to_be_bytes());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crc32, Colour, Layer, Render};
+    use crate::{Grid, Position};
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::parse("#.\n.E\n").unwrap();
+        let render = Render::map(&grid)
+            .layer(Layer::new([Position::new(1, 0), Position::new(5, 5)], Colour::RED).glyph('o'));
+        assert_eq!(render.to_string(), "#o\n.E\n");
+        assert_eq!(render.crop(Position::new(1, -1), 3, 2).to_string(), "o\n");
+        assert_eq!(
+            render.ansi().lines().next(),
+            Some("\x1b[38;2;160;160;160m#\x1b[38;2;230;60;60mo\x1b[0m")
+        );
+
+        let ppm = render.ppm(2);
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+
+        let png = render.png(1);
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR"));
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+    }
+}
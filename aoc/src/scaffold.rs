@@ -0,0 +1,266 @@
+//! Creates the crate of a new day from the templates in `templates/day`, with
+//! an empty example, and registers it in the workspace members, the runner's
+//! dependencies and the runner's registry.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const WORKSPACE_MANIFEST: &str = "Cargo.toml";
+pub const RUNNER_MANIFEST: &str = "runner/Cargo.toml";
+pub const RUNNER_REGISTRY: &str = "runner/src/registry.rs";
+
+const CARGO_TOML: &str = include_str!("../templates/day/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/day/lib.rs.tmpl");
+const EXAMPLE_TOML: &str = include_str!("../templates/day/example.toml.tmpl");
+
+/// The day's directory in the workspace, such as `2024/day01`.
+pub fn crate_dir(year: u16, day: u8) -> String {
+    format!("{}/day{:02}", year, day)
+}
+
+/// The day's package name, such as `aoc2024-day01`.
+pub fn crate_name(year: u16, day: u8) -> String {
+    format!("aoc{}-day{:02}", year, day)
+}
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{dd}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+fn invalid(path: &Path, message: impl Into<String>) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}: {}", path.display(), message.into()),
+    )
+}
+
+/// Where `item` goes among the items that `ours` picks out, which are kept
+/// sorted: before the first greater one, or else after the last one, or
+/// else at the end.
+fn sorted_position(items: &[String], item: &str, ours: impl Fn(&str) -> bool) -> usize {
+    let mut after = None;
+    for (i, other) in items.iter().enumerate() {
+        if !ours(other) {
+            continue;
+        }
+        if other.as_str() > item {
+            return i;
+        }
+        after = Some(i + 1);
+    }
+    after.unwrap_or(items.len())
+}
+
+/// Adds the day to the `members = [...]` of the workspace manifest.
+fn add_member(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let start = manifest
+        .find("members = [")
+        .map(|i| i + "members = [".len())
+        .ok_or("no workspace members")?;
+    let end = start + manifest[start..].find(']').ok_or("unclosed members")?;
+    let mut members = manifest[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|member| !member.is_empty())
+        .map(|member| member.trim_matches('"').to_string())
+        .collect::<Vec<_>>();
+    let member = crate_dir(year, day);
+    if members.contains(&member) {
+        return Err(format!("{} is already a member", member));
+    }
+    let at = sorted_position(&members, &member, |m| m.contains("/day"));
+    members.insert(at, member);
+    let members = members
+        .iter()
+        .map(|member| format!("\"{}\"", member))
+        .collect::<Vec<_>>();
+    Ok(format!(
+        "{} {}{}",
+        &manifest[..start],
+        members.join(", "),
+        &manifest[end..]
+    ))
+}
+
+fn lines(text: &str) -> Vec<String> {
+    text.lines().map(str::to_string).collect()
+}
+
+fn unlines(lines: &[String]) -> String {
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// Adds the day to the dependencies of the runner's manifest, next to the
+/// other days.
+fn add_dependency(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let mut lines = lines(manifest);
+    let name = crate_name(year, day);
+    if lines
+        .iter()
+        .any(|line| line.starts_with(&format!("{} ", name)))
+    {
+        return Err(format!("{} is already a dependency", name));
+    }
+    let dependency = format!(
+        "{} = {{ version = \"0.1.0\", path = \"../{}\" }}",
+        name,
+        crate_dir(year, day)
+    );
+    let at = sorted_position(&lines, &dependency, |line| line.starts_with("aoc"));
+    lines.insert(at, dependency);
+    Ok(unlines(&lines))
+}
+
+/// Adds the day to the `yearYYYY` function of the runner's registry, and
+/// adds that function when the day is the first of its year.
+fn add_registration(registry: &str, year: u16, day: u8) -> Result<String, String> {
+    let mut lines = lines(registry);
+    let function = format!("fn year{}(registry: &mut Registry) {{", year);
+    let register = format!(
+        "    registry.register(aoc{}_day{:02}::Day{:02});",
+        year, day, day
+    );
+    if !lines.contains(&function) {
+        let call = format!("    year{}(&mut registry);", year);
+        let at = sorted_position(&lines, &call, |line| line.starts_with("    year"));
+        if at == lines.len() {
+            return Err("no yearYYYY(&mut registry) calls".to_string());
+        }
+        lines.insert(at, call);
+        let new = [function.clone(), "}".to_string()];
+        match lines
+            .iter()
+            .position(|line| line.starts_with("fn year") && *line > function)
+        {
+            Some(at) => {
+                lines.splice(at..at, new.into_iter().chain([String::new()]));
+            }
+            None => lines.extend([String::new()].into_iter().chain(new)),
+        }
+    }
+    let start = lines.iter().position(|line| *line == function).unwrap() + 1;
+    let end = start + lines[start..].iter().position(|line| line == "}").unwrap();
+    let at = start + sorted_position(&lines[start..end], &register, |_| true);
+    lines.insert(at, register);
+    Ok(unlines(&lines))
+}
+
+fn edit(
+    path: &Path,
+    edit: impl Fn(&str, u16, u8) -> Result<String, String>,
+    year: u16,
+    day: u8,
+) -> io::Result<String> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    edit(&text, year, day).map_err(|e| invalid(path, e))
+}
+
+/// Creates the day's crate in the workspace at `root` and registers it,
+/// returning the files created and then the ones changed. Nothing is
+/// written when the day already exists or a file to change does not look as
+/// expected.
+pub fn new_day(root: &Path, year: u16, day: u8) -> io::Result<Vec<PathBuf>> {
+    let dir = root.join(crate_dir(year, day));
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+    let changed = [
+        (
+            WORKSPACE_MANIFEST,
+            add_member as fn(&str, u16, u8) -> Result<String, String>,
+        ),
+        (RUNNER_MANIFEST, add_dependency),
+        (RUNNER_REGISTRY, add_registration),
+    ]
+    .into_iter()
+    .map(|(path, add)| {
+        let path = root.join(path);
+        edit(&path, add, year, day).map(|text| (path, text))
+    })
+    .collect::<io::Result<Vec<_>>>()?;
+
+    let created = [
+        ("Cargo.toml", fill(CARGO_TOML, year, day)),
+        ("src/lib.rs", fill(LIB_RS, year, day)),
+        ("examples/example.txt", String::new()),
+        ("examples/example.toml", EXAMPLE_TOML.to_string()),
+    ];
+    let mut written = Vec::new();
+    for (path, text) in created {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, text)?;
+        written.push(path);
+    }
+    for (path, text) in changed {
+        fs::write(&path, text)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_dependency, add_member, add_registration};
+
+    #[test]
+    fn test_register() {
+        let manifest =
+            "[workspace]\nmembers = [ \"aoc\", \"2024/day01\", \"2024/day03\", \"runner\"]\n";
+        assert_eq!(
+            add_member(manifest, 2024, 2).unwrap(),
+            "[workspace]\nmembers = [ \"aoc\", \"2024/day01\", \"2024/day02\", \"2024/day03\", \"runner\"]\n"
+        );
+        assert!(add_member(manifest, 2024, 3).is_err());
+
+        let manifest = "[dependencies]\naoc = { version = \"0.1.0\", path = \"../aoc\" }\n\
+            aoc2024-day25 = { version = \"0.1.0\", path = \"../2024/day25\" }\n";
+        assert_eq!(
+            add_dependency(manifest, 2025, 1).unwrap(),
+            format!(
+                "{}aoc2025-day01 = {{ version = \"0.1.0\", path = \"../2025/day01\" }}\n",
+                manifest
+            )
+        );
+
+        let registry = "use aoc::Registry;\n\npub fn registry() -> Registry {\n    \
+            let mut registry = Registry::new();\n    year2024(&mut registry);\n    registry\n}\n\n\
+            fn year2024(registry: &mut Registry) {\n    \
+            registry.register(aoc2024_day01::Day01);\n    \
+            registry.register(aoc2024_day03::Day03::default());\n}\n";
+        assert_eq!(
+            add_registration(registry, 2024, 2).unwrap(),
+            registry.replace(
+                "Day01);\n",
+                "Day01);\n    registry.register(aoc2024_day02::Day02);\n"
+            )
+        );
+        assert_eq!(
+            add_registration(registry, 2025, 1).unwrap(),
+            registry.replace(
+                "    registry\n",
+                "    year2025(&mut registry);\n    registry\n"
+            ) + "\nfn year2025(registry: &mut Registry) {\n    \
+                registry.register(aoc2025_day01::Day01);\n}\n"
+        );
+        assert_eq!(
+            add_registration(registry, 2023, 25).unwrap(),
+            registry
+                .replace("    year2024", "    year2023(&mut registry);\n    year2024")
+                .replace(
+                    "fn year2024",
+                    "fn year2023(registry: &mut Registry) {\n    \
+                    registry.register(aoc2023_day25::Day25);\n}\n\nfn year2024"
+                )
+        );
+    }
+}
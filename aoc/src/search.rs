@@ -0,0 +1,288 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Costs from the start nodes, together with every predecessor that lies on
+/// a cheapest path, so that all optimal paths can be recovered.
+pub struct ShortestPaths<N, C> {
+    costs: HashMap<N, C>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> ShortestPaths<N, C> {
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    pub fn into_costs(self) -> HashMap<N, C> {
+        self.costs
+    }
+
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One cheapest path from a start node to `goal`, both included.
+    pub fn path_to(&self, goal: &N) -> Option<Vec<N>> {
+        self.costs.get(goal)?;
+        let mut path = vec![goal.clone()];
+        let mut seen = HashSet::from([goal.clone()]);
+        // Steps that cost nothing can make cycles of predecessors.
+        while let Some(previous) = self
+            .predecessors(path.last().unwrap())
+            .iter()
+            .find(|&node| !seen.contains(node))
+        {
+            seen.insert(previous.clone());
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every cheapest path from a start node to `goal` that visits no node
+    /// twice.
+    pub fn all_paths_to(&self, goal: &N) -> Vec<Vec<N>> {
+        if !self.costs.contains_key(goal) {
+            return Vec::new();
+        }
+        let mut paths = Vec::new();
+        let mut stack = vec![vec![goal.clone()]];
+        while let Some(path) = stack.pop() {
+            let previous = self.predecessors(path.last().unwrap());
+            if previous.is_empty() {
+                paths.push(path.into_iter().rev().collect());
+                continue;
+            }
+            for node in previous.iter().filter(|&node| !path.contains(node)) {
+                let mut longer = path.clone();
+                longer.push(node.clone());
+                stack.push(longer);
+            }
+        }
+        paths
+    }
+
+    /// The nodes that lie on at least one cheapest path to any of `goals`.
+    pub fn nodes_on_paths_to(&self, goals: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut seen = HashSet::new();
+        let mut stack = goals
+            .into_iter()
+            .filter(|goal| self.costs.contains_key(goal))
+            .collect::<Vec<_>>();
+        while let Some(node) = stack.pop() {
+            if !seen.contains(&node) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+                seen.insert(node);
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth first search where every step costs one.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> ShortestPaths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut costs = HashMap::new();
+    let mut predecessors: HashMap<N, Vec<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !costs.contains_key(&start) {
+            costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let cost = costs[&node] + 1;
+        for next in successors(&node) {
+            match costs.get(&next) {
+                Some(&known) if known < cost => {}
+                Some(_) => predecessors.entry(next).or_default().push(node.clone()),
+                None => {
+                    costs.insert(next.clone(), cost);
+                    predecessors.insert(next.clone(), vec![node.clone()]);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    ShortestPaths {
+        costs,
+        predecessors,
+    }
+}
+
+/// Dijkstra's algorithm from `starts`, keeping every predecessor on a
+/// cheapest path. Costs must not be negative, and start nodes get no
+/// predecessors even when a step that costs nothing leads back to one.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::new();
+    let mut predecessors: HashMap<N, Vec<N>> = HashMap::new();
+    // The heap holds indices into `nodes`, so that nodes need not be `Ord`.
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        costs.insert(start.clone(), C::default());
+        heap.push(Reverse((C::default(), nodes.len())));
+        nodes.push(start);
+    }
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if costs[&node] < cost {
+            continue;
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            match costs.get(&next) {
+                Some(&known) if known < next_cost => {}
+                Some(&known) if known == next_cost => {
+                    // Only start nodes have a cost but no predecessors.
+                    if let Some(previous) = predecessors.get_mut(&next) {
+                        if !previous.contains(&node) {
+                            previous.push(node.clone());
+                        }
+                    }
+                }
+                _ => {
+                    costs.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![node.clone()]);
+                    heap.push(Reverse((next_cost, nodes.len())));
+                    nodes.push(next);
+                }
+            }
+        }
+    }
+    ShortestPaths {
+        costs,
+        predecessors,
+    }
+}
+
+/// A* from `start` to the first node satisfying `is_goal`, returning its
+/// cost and path. The heuristic must never overestimate the remaining cost.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut nodes = vec![start];
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if costs[&node] < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            let mut path = vec![node];
+            while let Some(parent) = parents.get(path.last().unwrap()) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|&known| next_cost < known) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), node.clone());
+                heap.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    nodes.len(),
+                )));
+                nodes.push(next);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+    use crate::{tc_dist, Grid, Position};
+
+    #[test]
+    fn test_bfs() {
+        let grid = Grid::new(3, 3);
+        let start = Position::new(0, 0);
+        let end = Position::new(2, 2);
+        let paths = bfs([start], |&p| {
+            grid.neighbours(p).map(|(_, n)| n).collect::<Vec<_>>()
+        });
+        assert_eq!(paths.cost(&end), Some(4));
+        assert_eq!(paths.path_to(&end).unwrap().len(), 5);
+        assert_eq!(paths.all_paths_to(&end).len(), 6);
+        assert_eq!(paths.nodes_on_paths_to([Position::new(1, 0)]).len(), 2);
+    }
+
+    #[test]
+    fn test_weighted() {
+        // Going through 1 costs 1 + 1, going direct costs 3.
+        let successors = |&n: &u8| match n {
+            0 => vec![(1, 1), (2, 3)],
+            1 => vec![(2, 1)],
+            _ => vec![],
+        };
+        let paths = dijkstra([0u8], successors);
+        assert_eq!(paths.cost(&2), Some(2));
+        assert_eq!(paths.path_to(&2), Some(vec![0, 1, 2]));
+
+        // Free steps back and forth between 0, 1 and 2.
+        let successors = |&n: &u8| match n {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0), (2, 0), (3, 1)],
+            2 => vec![(1, 0)],
+            _ => vec![],
+        };
+        let paths = dijkstra([0u8], successors);
+        assert_eq!(paths.predecessors(&0), []);
+        assert_eq!(paths.cost(&2), Some(0));
+        assert_eq!(paths.path_to(&3), Some(vec![0, 1, 3]));
+        assert_eq!(paths.all_paths_to(&3), [[0, 1, 3]]);
+        assert_eq!(paths.all_paths_to(&1), [[0, 1]]);
+
+        let end = Position::new(5, 3);
+        let found = astar(
+            Position::new(0, 0),
+            |&p| {
+                Grid::new(6, 6)
+                    .neighbours(p)
+                    .map(|(_, n)| (n, 1))
+                    .collect::<Vec<_>>()
+            },
+            |&p| tc_dist(p, end),
+            |&p| p == end,
+        );
+        assert_eq!(found.map(|(cost, path)| (cost, path.len())), Some((8, 9)));
+    }
+}
@@ -0,0 +1,359 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    hint::black_box,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    animation::Recorder,
+    bench::Timings,
+    generate::{Generated, Rng},
+    render::Render,
+    Answer, IntoAnswer, ParseError,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+/// A tunable constant of a solution, such as the size of the grid, that can
+/// be set from text.
+pub trait Param: Display {
+    fn set(&mut self, value: &str) -> Result<(), String>;
+}
+
+impl<T: FromStr + Display> Param for T {
+    fn set(&mut self, value: &str) -> Result<(), String> {
+        *self = value
+            .parse()
+            .map_err(|_| format!("invalid value: {}", value))?;
+        Ok(())
+    }
+}
+
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// Tells alternative implementations of the same day apart.
+    const VARIANT: &'static str = "default";
+
+    type Parsed<'a>;
+    type Part1: IntoAnswer;
+    type Part2: IntoAnswer;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, ParseError>;
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Self::Part1;
+    fn part2(&self, parsed: &Self::Parsed<'_>) -> Self::Part2;
+
+    /// The day's tunable constants by name, for days whose examples use
+    /// different values than the real input.
+    fn params_mut(&mut self) -> Vec<(&'static str, &mut dyn Param)> {
+        Vec::new()
+    }
+
+    /// Why the current parameters cannot be solved with, if they cannot.
+    /// A parameter set to such a value goes back to what it was.
+    fn check_params(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// A picture of the solution, for days with something worth seeing.
+    fn render(&self, _parsed: &Self::Parsed<'_>) -> Option<Render> {
+        None
+    }
+
+    /// Records the states of a simulation, for days that step through one.
+    fn animate(&self, _parsed: &Self::Parsed<'_>, _recorder: &mut Recorder) {}
+
+    /// A random input for the current parameters, where what `size` counts
+    /// is up to the day, such as lines or the side of a grid.
+    fn generate(&self, _rng: &mut Rng, _size: usize) -> Option<Generated> {
+        None
+    }
+}
+
+/// Why a day could not be solved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// The input parsed, but a part found no answer in it.
+    NoAnswer {
+        day: u8,
+        part: Part,
+        reason: String,
+    },
+}
+
+impl SolveError {
+    fn check(day: u8, part: Part, answer: Result<Answer, String>) -> Result<Answer, SolveError> {
+        answer.map_err(|reason| SolveError::NoAnswer { day, part, reason })
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> SolveError {
+        SolveError::Parse(e)
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => e.fmt(f),
+            SolveError::NoAnswer { day, part, reason } => {
+                write!(f, "day {}, part {}: {}", day, part.number(), reason)
+            }
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// The answers of a single run, with how long parsing and each part took.
+#[derive(Clone, Debug)]
+pub struct Solved {
+    pub parse: Duration,
+    pub answers: Vec<(Part, Result<Answer, SolveError>, Duration)>,
+}
+
+/// The answer or error of each part, as one part failing leaves the others
+/// standing.
+pub type Answers = Vec<(Part, Result<Answer, SolveError>)>;
+
+/// Object safe view of a [`Solution`], so that days with different parsed
+/// and answer types can live side by side in a [`Registry`].
+pub trait Solver {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn variant(&self) -> &'static str;
+    fn params(&mut self) -> Vec<(&'static str, String)>;
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String>;
+    /// Writes back parameters saved from `params`, without checking them,
+    /// since the solver had them before.
+    fn restore_params(&mut self, saved: &[(&str, String)]);
+    /// Solves `parts` of `input`, failing rather than panicking on any input:
+    /// as a whole when parsing fails and otherwise part by part.
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Answers, SolveError>;
+    /// Solves once like `solve`, timing parsing and each part.
+    fn solve_timed(&self, input: &str, parts: &[Part]) -> Result<Solved, SolveError>;
+    /// Times parsing and each part separately, `iterations` times over.
+    fn bench(&self, input: &str, iterations: usize) -> Result<Timings, SolveError>;
+    fn render(&self, input: &str) -> Result<Option<Render>, ParseError>;
+    fn animate(&self, input: &str, recorder: &mut Recorder) -> Result<(), ParseError>;
+    /// A random input from `seed`, the same for the same seed and size.
+    fn generate(&self, seed: u64, size: usize) -> Option<Generated>;
+
+    /// Solves the one part, whether parsing or the part fails.
+    fn solve_part(&self, input: &str, part: Part) -> Result<Answer, SolveError> {
+        self.solve(input, &[part])?.remove(0).1
+    }
+}
+
+impl<S: Solution> Solver for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn variant(&self) -> &'static str {
+        S::VARIANT
+    }
+
+    fn params(&mut self) -> Vec<(&'static str, String)> {
+        self.params_mut()
+            .into_iter()
+            .map(|(name, param)| (name, param.to_string()))
+            .collect()
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        fn set<S: Solution>(solution: &mut S, name: &str, value: &str) -> Result<(), String> {
+            let (_, param) = solution
+                .params_mut()
+                .into_iter()
+                .find(|(n, _)| *n == name)
+                .ok_or_else(|| format!("day {} has no parameter {}", S::DAY, name))?;
+            param
+                .set(value)
+                .map_err(|e| format!("day {} parameter {}: {}", S::DAY, name, e))
+        }
+
+        let Some((_, old)) = self.params().into_iter().find(|(n, _)| *n == name) else {
+            return Err(format!("day {} has no parameter {}", S::DAY, name));
+        };
+        set(self, name, value)?;
+        self.check_params().map_err(|e| {
+            self.restore_params(&[(name, old)]);
+            format!("day {} parameter {}: {}", S::DAY, name, e)
+        })
+    }
+
+    fn restore_params(&mut self, saved: &[(&str, String)]) {
+        for (name, param) in self.params_mut() {
+            if let Some((_, value)) = saved.iter().find(|(n, _)| *n == name) {
+                // Whatever a parameter printed, it reads back.
+                let _ = param.set(value);
+            }
+        }
+    }
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Answers, SolveError> {
+        Ok(self
+            .solve_timed(input, parts)?
+            .answers
+            .into_iter()
+            .map(|(part, answer, _)| (part, answer))
+            .collect())
+    }
+
+    fn solve_timed(&self, input: &str, parts: &[Part]) -> Result<Solved, SolveError> {
+        let start = Instant::now();
+        let parsed = self.parse(input).map_err(|e| e.with_day(S::DAY))?;
+        let parse = start.elapsed();
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => self.part1(&parsed).into_answer(),
+                    Part::Two => self.part2(&parsed).into_answer(),
+                };
+                let elapsed = start.elapsed();
+                (part, SolveError::check(S::DAY, part, answer), elapsed)
+            })
+            .collect();
+        Ok(Solved { parse, answers })
+    }
+
+    fn bench(&self, input: &str, iterations: usize) -> Result<Timings, SolveError> {
+        let mut timings = Timings::default();
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let parsed = black_box(
+                self.parse(black_box(input))
+                    .map_err(|e| e.with_day(S::DAY))?,
+            );
+            timings.parse.push(start.elapsed());
+            let start = Instant::now();
+            let part1 = black_box(self.part1(&parsed));
+            timings.part1.push(start.elapsed());
+            SolveError::check(S::DAY, Part::One, part1.into_answer())?;
+            let start = Instant::now();
+            let part2 = black_box(self.part2(&parsed));
+            timings.part2.push(start.elapsed());
+            SolveError::check(S::DAY, Part::Two, part2.into_answer())?;
+        }
+        Ok(timings)
+    }
+
+    fn render(&self, input: &str) -> Result<Option<Render>, ParseError> {
+        let parsed = self.parse(input).map_err(|e| e.with_day(S::DAY))?;
+        Ok(Solution::render(self, &parsed))
+    }
+
+    fn animate(&self, input: &str, recorder: &mut Recorder) -> Result<(), ParseError> {
+        let parsed = self.parse(input).map_err(|e| e.with_day(S::DAY))?;
+        Solution::animate(self, &parsed, recorder);
+        Ok(())
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<Generated> {
+        Solution::generate(self, &mut Rng::new(seed), size)
+    }
+}
+
+#[derive(Default)]
+pub struct Registry {
+    solvers: Vec<Box<dyn Solver>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// Adds a solution, where the first one registered for a day of a year
+    /// is that day's default variant.
+    pub fn register<S: Solution + 'static>(&mut self, solution: S) {
+        assert!(
+            self.variant(S::YEAR, S::DAY, S::VARIANT).is_none(),
+            "{} day {} variant {} is registered twice",
+            S::YEAR,
+            S::DAY,
+            S::VARIANT
+        );
+        self.solvers.push(Box::new(solution));
+        self.solvers
+            .sort_by_key(|solver| (solver.year(), solver.day()));
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn Solver> {
+        self.variants(year, day).next()
+    }
+
+    pub fn variant(&self, year: u16, day: u8, name: &str) -> Option<&dyn Solver> {
+        self.variants(year, day)
+            .find(|solver| solver.variant() == name)
+    }
+
+    pub fn variants(&self, year: u16, day: u8) -> impl Iterator<Item = &dyn Solver> {
+        self.solvers
+            .iter()
+            .map(|solver| solver.as_ref())
+            .filter(move |solver| (solver.year(), solver.day()) == (year, day))
+    }
+
+    pub fn variants_mut(&mut self, year: u16, day: u8) -> impl Iterator<Item = &mut dyn Solver> {
+        self.solvers
+            .iter_mut()
+            .map(|solver| solver.as_mut() as &mut dyn Solver)
+            .filter(move |solver| (solver.year(), solver.day()) == (year, day))
+    }
+
+    /// The default variant of every day of `year`.
+    pub fn iter(&self, year: u16) -> impl Iterator<Item = &dyn Solver> {
+        let mut last_day = None;
+        self.solvers
+            .iter()
+            .map(|solver| solver.as_ref())
+            .filter(move |solver| solver.year() == year)
+            .filter(move |solver| last_day.replace(solver.day()) != Some(solver.day()))
+    }
+
+    /// The years with at least one solution, in order.
+    pub fn years(&self) -> Vec<u16> {
+        let mut years = self
+            .solvers
+            .iter()
+            .map(|solver| solver.year())
+            .collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    toml::{Document, Value},
+    Part,
+};
+
+pub const DEFAULT_PATH: &str = "submissions.toml";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Verdict {
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Verdict> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.name() == name)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Why an answer is not worth submitting.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The answer was already accepted.
+    Accepted,
+    /// Another answer was already accepted.
+    Solved(String),
+    /// The answer was already rejected.
+    Rejected(Verdict),
+    /// The answer is at least a known too high answer, or at most a known
+    /// too low one.
+    Beyond(Verdict, String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Accepted => write!(f, "already accepted"),
+            Refusal::Solved(answer) => write!(f, "already solved with {}", answer),
+            Refusal::Rejected(verdict) => write!(f, "already submitted and {}", verdict),
+            Refusal::Beyond(verdict, answer) => {
+                write!(f, "{} was already {}", answer, verdict)
+            }
+        }
+    }
+}
+
+/// Every answer submitted per year, day and part, and when the site allows
+/// the next one, kept in a TOML file with a `[YYYY.dayNN.partN]` table
+/// holding `wait_until` in seconds since the epoch, and a
+/// `[YYYY.dayNN.partN.attempts]` table from each answer to its verdict.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    document: Document,
+}
+
+fn table_name(year: u16, day: u8, part: Part) -> String {
+    format!("{}.day{:02}.part{}", year, day, part.number())
+}
+
+fn attempts_name(year: u16, day: u8, part: Part) -> String {
+    format!("{}.attempts", table_name(year, day, part))
+}
+
+impl History {
+    /// Reads `path`, which may not exist yet.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<History> {
+        let path = path.as_ref();
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let document = Document::parse(&text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })?;
+        Ok(History { document })
+    }
+
+    pub fn save(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.document.sort_tables();
+        fs::write(path, self.document.to_string())
+    }
+
+    /// The answers submitted for a part, in the order they were submitted.
+    pub fn attempts(&self, year: u16, day: u8, part: Part) -> Vec<(String, Verdict)> {
+        let Some(table) = self.document.table(&attempts_name(year, day, part)) else {
+            return Vec::new();
+        };
+        table
+            .iter()
+            .filter_map(|(answer, verdict)| {
+                let verdict = Verdict::from_name(verdict.as_str()?)?;
+                Some((answer.to_string(), verdict))
+            })
+            .collect()
+    }
+
+    /// Whether `answer` is worth submitting, given the earlier attempts.
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<(), Refusal> {
+        let attempts = self.attempts(year, day, part);
+        if let Some((_, verdict)) = attempts.iter().find(|(a, _)| a == answer) {
+            return match verdict {
+                Verdict::Correct => Err(Refusal::Accepted),
+                &verdict => Err(Refusal::Rejected(verdict)),
+            };
+        }
+        if let Some((correct, _)) = attempts.iter().find(|(_, v)| *v == Verdict::Correct) {
+            return Err(Refusal::Solved(correct.clone()));
+        }
+        let Ok(number) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let beyond = attempts.iter().find(|(a, verdict)| {
+            a.parse::<i128>().is_ok_and(|bound| match verdict {
+                Verdict::TooHigh => number >= bound,
+                Verdict::TooLow => number <= bound,
+                _ => false,
+            })
+        });
+        match beyond {
+            Some((bound, verdict)) => Err(Refusal::Beyond(*verdict, bound.clone())),
+            None => Ok(()),
+        }
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: Part, answer: &str, verdict: Verdict) {
+        self.document
+            .table_mut(&attempts_name(year, day, part))
+            .insert(answer, verdict.name());
+    }
+
+    /// How long until the site takes another answer for a part.
+    pub fn wait(&self, year: u16, day: u8, part: Part) -> Duration {
+        let until = self
+            .document
+            .table(&table_name(year, day, part))
+            .and_then(|table| table.get("wait_until"))
+            .and_then(Value::as_integer)
+            .and_then(|secs| u64::try_from(secs).ok())
+            .map(|secs| UNIX_EPOCH + Duration::from_secs(secs));
+        until
+            .and_then(|until| until.duration_since(SystemTime::now()).ok())
+            .unwrap_or(Duration::ZERO)
+    }
+
+    pub fn wait_for(&mut self, year: u16, day: u8, part: Part, wait: Duration) {
+        let until = SystemTime::now() + wait;
+        let secs = until
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs_f64().ceil() as i64);
+        self.document
+            .table_mut(&table_name(year, day, part))
+            .insert("wait_until", secs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{History, Refusal, Verdict};
+    use crate::{toml::Document, Part};
+
+    #[test]
+    fn test_check() {
+        let mut history = History::default();
+        assert_eq!(history.check(2024, 1, Part::One, "50"), Ok(()));
+        history.record(2024, 1, Part::One, "50", Verdict::TooHigh);
+        history.record(2024, 1, Part::One, "10", Verdict::TooLow);
+        history.record(2024, 1, Part::One, "co,de", Verdict::Wrong);
+        history.wait_for(2024, 1, Part::One, Duration::from_secs(60));
+
+        let document = Document::parse(&history.document.to_string()).unwrap();
+        let mut history = History { document };
+        assert_eq!(history.attempts(2024, 1, Part::One).len(), 3);
+        assert!(history.wait(2024, 1, Part::One) > Duration::from_secs(58));
+        assert_eq!(history.wait(2024, 1, Part::Two), Duration::ZERO);
+
+        let check = |history: &History, answer| history.check(2024, 1, Part::One, answer);
+        assert_eq!(
+            check(&history, "50"),
+            Err(Refusal::Rejected(Verdict::TooHigh))
+        );
+        assert_eq!(
+            check(&history, "51"),
+            Err(Refusal::Beyond(Verdict::TooHigh, "50".to_string()))
+        );
+        assert_eq!(
+            check(&history, "-3"),
+            Err(Refusal::Beyond(Verdict::TooLow, "10".to_string()))
+        );
+        assert_eq!(
+            check(&history, "co,de"),
+            Err(Refusal::Rejected(Verdict::Wrong))
+        );
+        assert_eq!(check(&history, "11"), Ok(()));
+        assert_eq!(check(&history, "ab,cd"), Ok(()));
+        assert_eq!(history.check(2024, 1, Part::Two, "50"), Ok(()));
+        assert_eq!(history.check(2023, 1, Part::One, "50"), Ok(()));
+
+        history.record(2024, 1, Part::One, "20", Verdict::Correct);
+        assert_eq!(check(&history, "20"), Err(Refusal::Accepted));
+        assert_eq!(
+            check(&history, "21"),
+            Err(Refusal::Solved("20".to_string()))
+        );
+    }
+}
@@ -0,0 +1,321 @@
+//! The small part of TOML the workspace needs for its own files: `[table]`
+//! headers, `key = value` lines with strings, integers, floats and booleans,
+//! and `#` comments. Dotted headers such as `[day01.abc]` are kept as one
+//! table name.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{json, parse, ParseError};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Integer(i128),
+    Float(f64),
+    String(String),
+}
+
+impl Value {
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Value::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Value::Float(x) => Some(*x),
+            Value::Integer(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Integer(i) => write!(f, "{}", i),
+            Value::Float(x) if x.fract() == 0.0 && x.is_finite() => write!(f, "{:.1}", x),
+            Value::Float(x) => write!(f, "{}", x),
+            Value::String(s) => json::write_str(f, s),
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Value {
+        Value::String(s)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(i: i64) -> Value {
+        Value::Integer(i.into())
+    }
+}
+
+impl From<f64> for Value {
+    fn from(x: f64) -> Value {
+        Value::Float(x)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Value {
+        Value::Bool(b)
+    }
+}
+
+/// Keys and values in the order they were read or inserted.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Table {
+    entries: Vec<(String, Value)>,
+}
+
+impl Table {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<Value>) {
+        let key = key.into();
+        let value = value.into();
+        match self.entries.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, old)) => *old = value,
+            None => self.entries.push((key, value)),
+        }
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        let index = self.entries.iter().position(|(k, _)| k == key)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// The keys before the first header live in the root table, named `""`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Document {
+    tables: Vec<(String, Table)>,
+}
+
+fn is_bare(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Reads a basic string starting right after its opening quote, returning
+/// it and the rest of `s` after the closing quote.
+fn string<'a>(input: &str, s: &'a str) -> Result<(String, &'a str), ParseError> {
+    let mut value = String::new();
+    let mut chars = s.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => return Ok((value, &s[index + 1..])),
+            '\\' => {
+                let escaped = match chars.next() {
+                    Some((_, '"')) => '"',
+                    Some((_, '\\')) => '\\',
+                    Some((_, 'n')) => '\n',
+                    Some((_, 'r')) => '\r',
+                    Some((_, 't')) => '\t',
+                    Some((start, 'u')) => {
+                        let hex = s.get(start + 1..start + 5).unwrap_or_default();
+                        let c = u32::from_str_radix(hex, 16).ok().and_then(char::from_u32);
+                        chars.nth(3);
+                        c.ok_or_else(|| ParseError::new(input, &s[index..], "a unicode escape"))?
+                    }
+                    _ => return Err(ParseError::new(input, &s[index..], "an escape")),
+                };
+                value.push(escaped);
+            }
+            c => value.push(c),
+        }
+    }
+    Err(ParseError::new(input, &s[s.len()..], "`\"`"))
+}
+
+fn value<'a>(input: &str, s: &'a str) -> Result<(Value, &'a str), ParseError> {
+    if let Some(quoted) = s.strip_prefix('"') {
+        let (value, rest) = string(input, quoted)?;
+        return Ok((Value::String(value), rest));
+    }
+    let end = s.find([' ', '\t', '#']).unwrap_or(s.len());
+    let (token, rest) = s.split_at(end);
+    let digits = token.replace('_', "");
+    let value = match token {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => match digits.parse() {
+            Ok(i) => Value::Integer(i),
+            Err(_) => Value::Float(
+                digits
+                    .parse()
+                    .map_err(|_| ParseError::new(input, s, "a value"))?,
+            ),
+        },
+    };
+    Ok((value, rest))
+}
+
+fn key<'a>(input: &str, s: &'a str) -> Result<(String, &'a str), ParseError> {
+    match s.strip_prefix('"') {
+        Some(quoted) => string(input, quoted),
+        None => {
+            let end = s.find(|c| !is_bare(c)).unwrap_or(s.len());
+            match end {
+                0 => Err(ParseError::new(input, s, "a key")),
+                _ => Ok((s[..end].to_string(), &s[end..])),
+            }
+        }
+    }
+}
+
+fn end_of_line(input: &str, s: &str) -> Result<(), ParseError> {
+    let rest = s.trim_start();
+    if rest.is_empty() || rest.starts_with('#') {
+        Ok(())
+    } else {
+        Err(ParseError::new(input, rest, "end of line"))
+    }
+}
+
+impl Document {
+    pub fn parse(input: &str) -> Result<Document, ParseError> {
+        let mut document = Document::default();
+        let mut current = String::new();
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[') {
+                let Some((name, rest)) = header.split_once(']') else {
+                    return Err(ParseError::new(input, &header[header.len()..], "`]`"));
+                };
+                let name = name.trim();
+                if name.is_empty() || !name.chars().all(|c| is_bare(c) || c == '.') {
+                    return Err(ParseError::new(input, name, "a table name"));
+                }
+                end_of_line(input, rest)?;
+                current = name.to_string();
+                document.table_mut(&current);
+                continue;
+            }
+            let (key, rest) = key(input, line)?;
+            let rest = parse::strip_prefix(input, rest.trim_start(), "=")?;
+            let (value, rest) = value(input, rest.trim_start())?;
+            end_of_line(input, rest)?;
+            document.table_mut(&current).insert(key, value);
+        }
+        Ok(document)
+    }
+
+    pub fn table(&self, name: &str) -> Option<&Table> {
+        self.tables
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, table)| table)
+    }
+
+    /// The table called `name`, added at the end if it is missing.
+    pub fn table_mut(&mut self, name: &str) -> &mut Table {
+        let index = match self.tables.iter().position(|(n, _)| n == name) {
+            Some(index) => index,
+            // The root table has no header, so it must come first.
+            None if name.is_empty() => {
+                self.tables.insert(0, (String::new(), Table::default()));
+                0
+            }
+            None => {
+                self.tables.push((name.to_string(), Table::default()));
+                self.tables.len() - 1
+            }
+        };
+        &mut self.tables[index].1
+    }
+
+    pub fn root(&self) -> Option<&Table> {
+        self.table("")
+    }
+
+    pub fn tables(&self) -> impl Iterator<Item = (&str, &Table)> {
+        self.tables
+            .iter()
+            .map(|(name, table)| (name.as_str(), table))
+    }
+
+    /// Sorts by name, which keeps the root table first.
+    pub fn sort_tables(&mut self) {
+        self.tables.sort_by(|(a, _), (b, _)| a.cmp(b));
+    }
+}
+
+impl Display for Document {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (name, table) in &self.tables {
+            if !name.is_empty() {
+                if !first {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[{}]", name)?;
+            }
+            for (key, value) in &table.entries {
+                if !key.is_empty() && key.chars().all(is_bare) {
+                    writeln!(f, "{} = {}", key, value)?;
+                } else {
+                    writeln!(f, "{} = {}", Value::from(key.as_str()), value)?;
+                }
+            }
+            first = false;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Document, Value};
+
+    #[test]
+    fn test_round_trip() {
+        let input = "top = true\n\n# Comment\n[day01.ab12]\npart1 = 1_234 # trailing\n\
+                     part2 = \"co,de\\n\"\nmillis = 0.5\n";
+        let document = Document::parse(input).unwrap();
+        let table = document.table("day01.ab12").unwrap();
+        assert_eq!(table.get("part1"), Some(&Value::Integer(1234)));
+        assert_eq!(table.get("part2").and_then(Value::as_str), Some("co,de\n"));
+        assert_eq!(table.get("millis"), Some(&Value::Float(0.5)));
+        assert_eq!(Document::parse(&document.to_string()), Ok(document));
+
+        let error = Document::parse("[a]\nx = 1 2\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+    }
+}
@@ -0,0 +1,281 @@
+//! Waits for files to change, with inotify rather than by polling. Only
+//! Linux has inotify, so elsewhere [`Watcher::new`] fails.
+//!
+//! Directories are watched rather than files, since editors often save by
+//! writing a new file and renaming it over the old one.
+
+use std::{
+    collections::HashMap,
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// How long [`Watcher::new`] waits for things to settle: changes that come
+/// within this long of each other are reported together, as editors and
+/// builds touch several files in a row.
+const SETTLE: Duration = Duration::from_millis(100);
+
+const IN_MOVED_FROM: u32 = 0x40;
+const IN_MOVED_TO: u32 = 0x80;
+const IN_CLOSE_WRITE: u32 = 0x8;
+const IN_CREATE: u32 = 0x100;
+const IN_DELETE: u32 = 0x200;
+const IN_ISDIR: u32 = 0x4000_0000;
+const MASK: u32 = IN_CLOSE_WRITE | IN_MOVED_FROM | IN_MOVED_TO | IN_CREATE | IN_DELETE;
+
+struct Event {
+    watch: i32,
+    mask: u32,
+    name: OsString,
+}
+
+#[cfg(target_os = "linux")]
+mod sys {
+    use std::{
+        ffi::{CString, OsStr},
+        fs::File,
+        io::{self, Read},
+        os::{
+            fd::{AsRawFd, FromRawFd},
+            raw::{c_char, c_int, c_ulong},
+            unix::ffi::OsStrExt,
+        },
+        path::Path,
+        time::Duration,
+    };
+
+    use super::{Event, MASK};
+
+    const IN_CLOEXEC: c_int = 0o2000000;
+    const POLLIN: i16 = 1;
+
+    #[repr(C)]
+    struct PollFd {
+        fd: c_int,
+        events: i16,
+        revents: i16,
+    }
+
+    extern "C" {
+        fn inotify_init1(flags: c_int) -> c_int;
+        fn inotify_add_watch(fd: c_int, path: *const c_char, mask: u32) -> c_int;
+        fn poll(fds: *mut PollFd, nfds: c_ulong, timeout: c_int) -> c_int;
+    }
+
+    pub struct Inotify {
+        file: File,
+    }
+
+    impl Inotify {
+        pub fn new() -> io::Result<Inotify> {
+            // Close on exec, so that a process that replaces itself does not
+            // keep watching on behalf of its old self.
+            let fd = unsafe { inotify_init1(IN_CLOEXEC) };
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            // SAFETY: the descriptor is fresh and owned by nothing else.
+            let file = unsafe { File::from_raw_fd(fd) };
+            Ok(Inotify { file })
+        }
+
+        pub fn add(&self, dir: &Path) -> io::Result<i32> {
+            let path = CString::new(dir.as_os_str().as_bytes())?;
+            let watch = unsafe { inotify_add_watch(self.file.as_raw_fd(), path.as_ptr(), MASK) };
+            match watch < 0 {
+                true => Err(io::Error::last_os_error()),
+                false => Ok(watch),
+            }
+        }
+
+        /// The next events, waiting for them up to `timeout` or for ever.
+        pub fn read(&mut self, timeout: Option<Duration>) -> io::Result<Vec<Event>> {
+            let mut fds = PollFd {
+                fd: self.file.as_raw_fd(),
+                events: POLLIN,
+                revents: 0,
+            };
+            let millis = timeout.map_or(-1, |timeout| timeout.as_millis() as c_int);
+            if unsafe { poll(&mut fds, 1, millis) } < 0 {
+                let e = io::Error::last_os_error();
+                return match e.kind() {
+                    io::ErrorKind::Interrupted => Ok(Vec::new()),
+                    _ => Err(e),
+                };
+            }
+            if fds.revents & POLLIN == 0 {
+                return Ok(Vec::new());
+            }
+            // Enough for any event, whose name is at most 255 bytes.
+            let mut buffer = [0; 4096];
+            let length = self.file.read(&mut buffer)?;
+            let mut events = Vec::new();
+            let mut rest = &buffer[..length];
+            while rest.len() >= 16 {
+                let field = |i: usize| rest[i..i + 4].try_into().unwrap();
+                let name_length = u32::from_ne_bytes(field(12)) as usize;
+                let name = &rest[16..16 + name_length];
+                let name = &name[..name.iter().position(|&b| b == 0).unwrap_or(name.len())];
+                events.push(Event {
+                    watch: i32::from_ne_bytes(field(0)),
+                    mask: u32::from_ne_bytes(field(4)),
+                    name: OsStr::from_bytes(name).to_os_string(),
+                });
+                rest = &rest[16 + name_length..];
+            }
+            Ok(events)
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod sys {
+    use std::{io, path::Path, time::Duration};
+
+    use super::Event;
+
+    pub struct Inotify;
+
+    impl Inotify {
+        pub fn new() -> io::Result<Inotify> {
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "watching needs inotify, which only Linux has",
+            ))
+        }
+
+        pub fn add(&self, _dir: &Path) -> io::Result<i32> {
+            unreachable!()
+        }
+
+        pub fn read(&mut self, _timeout: Option<Duration>) -> io::Result<Vec<Event>> {
+            unreachable!()
+        }
+    }
+}
+
+pub struct Watcher {
+    inotify: sys::Inotify,
+    dirs: HashMap<i32, PathBuf>,
+    paths: Vec<PathBuf>,
+    settle: Duration,
+}
+
+impl Watcher {
+    /// Watches each path, a file or a directory with everything under it. A
+    /// file need not exist yet, but the directory it goes in does.
+    pub fn new(paths: impl IntoIterator<Item = impl AsRef<Path>>) -> io::Result<Watcher> {
+        Watcher::with_settle(paths, SETTLE)
+    }
+
+    /// Like [`Watcher::new`], but reporting changes together when they come
+    /// within `settle` of each other.
+    pub fn with_settle(
+        paths: impl IntoIterator<Item = impl AsRef<Path>>,
+        settle: Duration,
+    ) -> io::Result<Watcher> {
+        let mut watcher = Watcher {
+            inotify: sys::Inotify::new()?,
+            dirs: HashMap::new(),
+            paths: Vec::new(),
+            settle,
+        };
+        for path in paths {
+            let path = path.as_ref();
+            match path.is_dir() {
+                true => watcher.add_tree(path)?,
+                false => watcher.add(path.parent().unwrap_or(Path::new("")))?,
+            }
+            watcher.paths.push(path.to_path_buf());
+        }
+        Ok(watcher)
+    }
+
+    fn add(&mut self, dir: &Path) -> io::Result<()> {
+        let target = match dir.as_os_str().is_empty() {
+            true => Path::new("."),
+            false => dir,
+        };
+        let watch = self
+            .inotify
+            .add(target)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", target.display(), e)))?;
+        self.dirs.insert(watch, dir.to_path_buf());
+        Ok(())
+    }
+
+    fn add_tree(&mut self, dir: &Path) -> io::Result<()> {
+        self.add(dir)?;
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                self.add_tree(&path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Blocks until a watched path changes, and returns every path that
+    /// changed until things settled down.
+    pub fn wait(&mut self) -> io::Result<Vec<PathBuf>> {
+        let mut changed = Vec::new();
+        loop {
+            let timeout = (!changed.is_empty()).then_some(self.settle);
+            let events = self.inotify.read(timeout)?;
+            if events.is_empty() && timeout.is_some() {
+                break;
+            }
+            for event in events {
+                let Some(dir) = self.dirs.get(&event.watch) else {
+                    continue;
+                };
+                let path = dir.join(&event.name);
+                if !self.paths.iter().any(|watched| path.starts_with(watched)) {
+                    continue;
+                }
+                if event.mask & (IN_ISDIR | IN_CREATE) == IN_ISDIR | IN_CREATE {
+                    // The directory may be gone again already.
+                    let _ = self.add_tree(&path);
+                }
+                changed.push(path);
+            }
+        }
+        changed.sort();
+        changed.dedup();
+        Ok(changed)
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use std::{env, fs, process, time::Duration};
+
+    use super::Watcher;
+
+    #[test]
+    fn test_wait() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        let input = dir.join("input.txt");
+        // Long enough for a loaded machine, and only ever waited out once per
+        // call, as the changes are all made before waiting for them.
+        let mut watcher =
+            Watcher::with_settle([&input, &dir.join("src")], Duration::from_secs(1)).unwrap();
+
+        fs::write(dir.join("other.txt"), "ignored").unwrap();
+        fs::write(dir.join("input.txt"), "1 2 3").unwrap();
+        fs::create_dir(dir.join("src/nested")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        assert_eq!(
+            watcher.wait().unwrap(),
+            [input, dir.join("src/lib.rs"), dir.join("src/nested")]
+        );
+
+        fs::write(dir.join("src/nested/mod.rs"), "").unwrap();
+        assert_eq!(watcher.wait().unwrap(), [dir.join("src/nested/mod.rs")]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
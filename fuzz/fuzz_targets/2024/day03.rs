@@ -0,0 +1,5 @@
+#![no_main]
+
+libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+    aoc::fuzz::fuzz(&aoc2024_day03::Day03, data);
+});
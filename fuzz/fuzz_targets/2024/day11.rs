@@ -0,0 +1,6 @@
+#![no_main]
+
+libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+    aoc::fuzz::fuzz(&aoc2024_day11::Day11::default(), data);
+    aoc::fuzz::fuzz(&aoc2024_day11::Day11Post::default(), data);
+});
@@ -0,0 +1,6 @@
+#![no_main]
+
+libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+    aoc::fuzz::fuzz(&aoc2024_day19::Day19, data);
+    aoc::fuzz::fuzz(&aoc2024_day19::Day19Post, data);
+});
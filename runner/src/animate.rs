@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use aoc::{
+    animation::Recorder,
+    input::{self, Source},
+    Position, Registry,
+};
+
+use crate::cli::{self, Args, Params};
+
+/// Reads `X,Y,W,H`.
+fn parse_crop(s: &str) -> Result<(Position, i64, i64), String> {
+    let numbers = s
+        .split(',')
+        .map(|n| n.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>();
+    match numbers.as_deref() {
+        Ok(&[x, y, width, height]) => Ok((Position::new(x, y), width, height)),
+        _ => Err(format!("expected --crop X,Y,W,H, found {}", s)),
+    }
+}
+
+pub fn animate(mut registry: Registry, mut args: Args) -> Result<(), String> {
+    let every = args.parsed_option("--every")?.unwrap_or(1);
+    let crop = args.option("--crop")?.map(|s| parse_crop(&s)).transpose()?;
+    let scale = args.parsed_option("--scale")?.unwrap_or(4);
+    let delay = args.parsed_option("--delay")?.unwrap_or(100);
+    let variant = args.option("--variant")?;
+    let params = Params::from_args(&mut args)?;
+    let year = cli::year(&mut args, &registry)?;
+    let day = cli::parse_day(&args.positional().ok_or(crate::USAGE)?)?;
+    let output = args.positional().ok_or(crate::USAGE)?;
+    let source = Source::from_arg(args.positional().as_deref(), year, day);
+    args.finish()?;
+    params.apply(&mut registry, year, Some(day))?;
+    let solver = cli::solver(&registry, year, day, variant.as_deref())?;
+    let input = input::read(&source).map_err(|e| e.to_string())?;
+
+    let mut recorder = Recorder::create(&output)
+        .map_err(|e| format!("{}: {}", output, e))?
+        .every(every)
+        .scale(scale)
+        .delay(Duration::from_millis(delay));
+    if let Some((origin, width, height)) = crop {
+        recorder = recorder.crop(origin, width, height);
+    }
+    solver
+        .animate(&input, &mut recorder)
+        .map_err(|e| e.to_string())?;
+    let seen = recorder.seen();
+    let written = recorder
+        .finish()
+        .map_err(|e| format!("{}: {}", output, e))?;
+    if seen == 0 {
+        return Err(format!("day {} has nothing to animate", day));
+    }
+    println!("Wrote {} of {} frames to {}", written, seen, output);
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use aoc::{
+    bench::{self, Baseline},
+    input::{self, Source},
+    Registry, Solver,
+};
+
+use crate::cli::{self, Args, Params};
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+pub fn bench(mut registry: Registry, mut args: Args) -> Result<(), String> {
+    let all = args.flag("--all");
+    let iterations = args
+        .parsed_option("-n")?
+        .unwrap_or(DEFAULT_ITERATIONS)
+        .max(1);
+    let save = args.option("--save")?;
+    let variant = args.option("--variant")?;
+    let generate = args.parsed_option::<usize>("--generate")?;
+    let seed = args.parsed_option("--seed")?.unwrap_or(0);
+    let params = Params::from_args(&mut args)?;
+    let baseline = args
+        .option("--baseline")?
+        .map(|path| Baseline::load(path).map_err(|e| e.to_string()))
+        .transpose()?;
+    let year = cli::year(&mut args, &registry)?;
+    let (day, source) = if all {
+        (None, None)
+    } else {
+        let day = cli::parse_day(&args.positional().ok_or(crate::USAGE)?)?;
+        (Some(day), args.positional())
+    };
+    args.finish()?;
+    if all && variant.is_some() {
+        return Err("--variant needs a day".to_string());
+    }
+    if generate.is_some() && source.is_some() {
+        return Err("--generate takes the place of INPUT".to_string());
+    }
+    params.apply(&mut registry, year, day)?;
+    let solvers: Vec<(&dyn Solver, Source)> = match day {
+        None => registry
+            .iter(year)
+            .map(|solver| (solver, Source::from_arg(None, year, solver.day())))
+            .collect(),
+        Some(day) => {
+            let solver = cli::solver(&registry, year, day, variant.as_deref())?;
+            vec![(solver, Source::from_arg(source.as_deref(), year, day))]
+        }
+    };
+
+    if cfg!(debug_assertions) {
+        eprintln!("warning: benchmarking a debug build, pass --release to cargo");
+    }
+    let mut recorded = Baseline::default();
+    for (solver, source) in solvers {
+        let input = match generate {
+            Some(size) => solver
+                .generate(seed, size)
+                .map(|generated| generated.input)
+                .ok_or_else(|| {
+                    format!(
+                        "day {} cannot generate an input with these parameters",
+                        solver.day()
+                    )
+                }),
+            None => input::read(&source).map_err(|e| e.to_string()),
+        };
+        let input = match input {
+            Ok(input) => input,
+            Err(e) if all => {
+                println!("Day {:02}: skipped, {}", solver.day(), e);
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        let timings = match cli::catch(|| solver.bench(&input, iterations)) {
+            Ok(timings) => timings.map_err(|e| e.to_string()),
+            Err(message) => Err(format!("panicked: {}", message)),
+        };
+        let timings = match timings {
+            Ok(timings) => timings,
+            Err(e) if all => {
+                println!("Day {:02}: skipped, {}", solver.day(), e);
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        println!("Day {:02} ({} iterations)", solver.day(), iterations);
+        for (phase, summary) in timings.summaries() {
+            let median = bench::millis(summary.median);
+            let change = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.median(year, solver.day(), &input, phase))
+                .map_or(String::new(), |was| {
+                    format!("  {:+.1}% vs {:.3} ms", (median / was - 1.0) * 100.0, was)
+                });
+            println!(
+                "  {:<5}  median {:>10.3} ms  min {:>10.3} ms  max {:>10.3} ms{}",
+                phase,
+                median,
+                bench::millis(summary.min),
+                bench::millis(summary.max),
+                change
+            );
+        }
+        recorded.record(year, solver.day(), &input, &timings);
+    }
+    if let Some(path) = save {
+        recorded
+            .save(&path)
+            .map_err(|e| format!("{}: {}", path, e))?;
+        println!("Baseline written to {}", path);
+    }
+    Ok(())
+}
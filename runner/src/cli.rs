@@ -0,0 +1,268 @@
+use std::{
+    collections::VecDeque,
+    env, fs,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+};
+
+use aoc::{toml::Document, Part, Registry, Solver};
+
+pub struct Args {
+    args: VecDeque<String>,
+}
+
+impl Args {
+    pub fn new(args: impl IntoIterator<Item = String>) -> Args {
+        Args {
+            args: args.into_iter().collect(),
+        }
+    }
+
+    pub fn flag(&mut self, name: &str) -> bool {
+        match self.args.iter().position(|arg| arg == name) {
+            Some(index) => {
+                self.args.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn option(&mut self, name: &str) -> Result<Option<String>, String> {
+        let prefix = format!("{}=", name);
+        let Some(index) = self
+            .args
+            .iter()
+            .position(|arg| arg == name || arg.starts_with(&prefix))
+        else {
+            return Ok(None);
+        };
+        let arg = self.args.remove(index).unwrap();
+        match arg.strip_prefix(&prefix) {
+            Some(value) => Ok(Some(value.to_string())),
+            None => self
+                .args
+                .remove(index)
+                .map(Some)
+                .ok_or_else(|| format!("missing value for {}", name)),
+        }
+    }
+
+    pub fn parsed_option<T: FromStr>(&mut self, name: &str) -> Result<Option<T>, String> {
+        self.option(name)?
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| format!("invalid value for {}: {}", name, value))
+            })
+            .transpose()
+    }
+
+    pub fn positional(&mut self) -> Option<String> {
+        let index = self
+            .args
+            .iter()
+            .position(|arg| arg == "-" || !arg.starts_with('-'))?;
+        self.args.remove(index)
+    }
+
+    pub fn finish(self) -> Result<(), String> {
+        match self.args.front() {
+            Some(arg) => Err(format!("unexpected argument: {}", arg)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Format, ()> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+const YEAR_VAR: &str = "AOC_YEAR";
+
+fn parse_year(s: &str) -> Result<u16, String> {
+    s.parse()
+        .ok()
+        .filter(|&year| year >= 2015)
+        .ok_or_else(|| format!("not a year: {}", s))
+}
+
+/// The year in `--year` or else in `AOC_YEAR`, if either is set.
+pub fn year_arg(args: &mut Args) -> Result<Option<u16>, String> {
+    match args.option("--year")? {
+        Some(year) => parse_year(&year).map(Some),
+        None => env::var(YEAR_VAR)
+            .ok()
+            .filter(|year| !year.is_empty())
+            .map(|year| parse_year(&year))
+            .transpose(),
+    }
+}
+
+/// The year from [`year_arg`], or else the latest one with solutions, and
+/// only ever a year with solutions.
+pub fn year(args: &mut Args, registry: &Registry) -> Result<u16, String> {
+    let years = registry.years();
+    let Some(year) = year_arg(args)?.or(years.last().copied()) else {
+        return Err("no solutions are registered".to_string());
+    };
+    if !years.contains(&year) {
+        let known = years.iter().map(u16::to_string).collect::<Vec<_>>();
+        return Err(format!(
+            "no solutions for {}, only for: {}",
+            year,
+            known.join(", ")
+        ));
+    }
+    Ok(year)
+}
+
+/// The year from [`year_arg`], or else the latest one with solutions, for
+/// commands that also work on years without any.
+pub fn any_year(args: &mut Args, registry: &Registry) -> Result<u16, String> {
+    year_arg(args)?
+        .or(registry.years().last().copied())
+        .ok_or_else(|| "no year given, pass --year YYYY".to_string())
+}
+
+pub fn parse_day(s: &str) -> Result<u8, String> {
+    s.parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("not a day: {}", s))
+}
+
+pub fn parse_parts(part: Option<u8>) -> Result<Vec<Part>, String> {
+    match part {
+        None => Ok(Part::BOTH.to_vec()),
+        Some(n) => Part::from_number(n)
+            .map(|part| vec![part])
+            .ok_or_else(|| format!("not a part: {}", n)),
+    }
+}
+
+/// Runs `f`, turning a panic into its message instead of unwinding further.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+    result.map_err(|payload| match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or("panicked".to_string(), |message| message.to_string()),
+    })
+}
+
+/// The named variant of `day` of `year`, or its default one.
+pub fn solver<'a>(
+    registry: &'a Registry,
+    year: u16,
+    day: u8,
+    variant: Option<&str>,
+) -> Result<&'a dyn Solver, String> {
+    let Some(name) = variant else {
+        return registry
+            .get(year, day)
+            .ok_or_else(|| format!("day {} of {} is not solved yet", day, year));
+    };
+    registry.variant(year, day, name).ok_or_else(|| {
+        let known = registry
+            .variants(year, day)
+            .map(|solver| solver.variant())
+            .collect::<Vec<_>>();
+        format!(
+            "day {} has no variant {}, only: {}",
+            day,
+            name,
+            known.join(", ")
+        )
+    })
+}
+
+/// Parameter overrides from `--params PATH`, a TOML file with a `[dayNN]`
+/// table per day of the chosen year or a `[YYYY.dayNN]` table per day of
+/// any year, and from any number of `--param name=value`, which apply to
+/// the chosen day and win over the file.
+pub struct Params {
+    file: Vec<(Option<u16>, u8, String, String)>,
+    args: Vec<(String, String)>,
+}
+
+impl Params {
+    pub fn from_args(args: &mut Args) -> Result<Params, String> {
+        let mut file = Vec::new();
+        if let Some(path) = args.option("--params")? {
+            let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path, e))?;
+            let document = Document::parse(&text).map_err(|e| format!("{}: {}", path, e))?;
+            for (name, table) in document.tables() {
+                let (year, day) = match name.split_once('.') {
+                    Some((year, day)) => (parse_year(year).ok(), Some(day)),
+                    None => (None, Some(name)),
+                };
+                let day = day
+                    .filter(|_| year.is_some() || !name.contains('.'))
+                    .and_then(|day| day.strip_prefix("day"))
+                    .and_then(|day| parse_day(day).ok())
+                    .ok_or_else(|| {
+                        format!(
+                            "{}: expected a [dayNN] or [YYYY.dayNN] table, found [{}]",
+                            path, name
+                        )
+                    })?;
+                for (key, value) in table.iter() {
+                    let value = value
+                        .as_str()
+                        .map_or_else(|| value.to_string(), str::to_string);
+                    file.push((year, day, key.to_string(), value));
+                }
+            }
+        }
+        let mut params = Vec::new();
+        while let Some(param) = args.option("--param")? {
+            let (name, value) = param
+                .split_once('=')
+                .ok_or_else(|| format!("expected --param name=value, found {}", param))?;
+            params.push((name.to_string(), value.to_string()));
+        }
+        Ok(Params { file, args: params })
+    }
+
+    /// Sets the parameters on every variant of the days of `year`, where
+    /// `day` is the day that `--param` applies to.
+    pub fn apply(&self, registry: &mut Registry, year: u16, day: Option<u8>) -> Result<(), String> {
+        let args = match (day, self.args.is_empty()) {
+            (_, true) => Vec::new(),
+            (Some(day), false) => self
+                .args
+                .iter()
+                .map(|(name, value)| (None, day, name.clone(), value.clone()))
+                .collect(),
+            (None, false) => return Err("--param needs a day".to_string()),
+        };
+        for (from, day, name, value) in self.file.iter().chain(&args) {
+            if from.is_some_and(|from| from != year) {
+                continue;
+            }
+            for solver in registry.variants_mut(year, *day) {
+                solver.set_param(name, value)?;
+            }
+        }
+        Ok(())
+    }
+}
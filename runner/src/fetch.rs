@@ -0,0 +1,55 @@
+use std::{env, io};
+
+use aoc::{
+    client::{self, Client, Fetched},
+    Registry,
+};
+
+use crate::cli::{self, Args};
+
+const SESSION_VAR: &str = "AOC_SESSION";
+const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// A client of the site in `--base-url`, `AOC_BASE_URL` or the real one,
+/// with the session token in `AOC_SESSION`.
+pub fn client(args: &mut Args) -> Result<(Client, String), String> {
+    let base_url = match args.option("--base-url")? {
+        Some(base_url) => base_url,
+        None => env::var(BASE_URL_VAR).unwrap_or_else(|_| client::DEFAULT_BASE_URL.to_string()),
+    };
+    let session = env::var(SESSION_VAR)
+        .ok()
+        .filter(|session| !session.is_empty());
+    Ok((Client::with_defaults(&base_url, session), base_url))
+}
+
+/// `e`, with a hint on where the session token goes when it is missing or
+/// was turned down.
+pub fn client_error(e: io::Error, base_url: &str) -> String {
+    match e.kind() {
+        io::ErrorKind::PermissionDenied => format!(
+            "{}\nset {} to the session cookie of {}",
+            e, SESSION_VAR, base_url
+        ),
+        _ => e.to_string(),
+    }
+}
+
+/// Downloads the input of a day into the inputs directory, unless it is
+/// there already. Any year can be fetched, solved or not.
+pub fn fetch(registry: Registry, mut args: Args) -> Result<(), String> {
+    let (client, base_url) = client(&mut args)?;
+    let year = cli::any_year(&mut args, &registry)?;
+    let day = cli::parse_day(&args.positional().ok_or(crate::USAGE)?)?;
+    args.finish()?;
+    match client
+        .fetch(year, day)
+        .map_err(|e| client_error(e, &base_url))?
+    {
+        Fetched::Cached(path) => println!("Day {:02}: cached in {}", day, path.display()),
+        Fetched::Downloaded(path) => {
+            println!("Day {:02}: downloaded to {}", day, path.display())
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use aoc::{examples, fuzz, generate::Rng, Registry, Solver};
+
+use crate::cli::{self, Args, Params};
+
+const DEFAULT_ITERATIONS: usize = 10000;
+const DEFAULT_SIZE: usize = 10;
+
+/// Feeds every variant of a day random mutations of generated inputs and of
+/// the day's examples, stopping at the first input that makes one panic.
+pub fn fuzz(mut registry: Registry, mut args: Args) -> Result<(), String> {
+    let iterations = args.parsed_option("-n")?.unwrap_or(DEFAULT_ITERATIONS);
+    let seed: u64 = args.parsed_option("--seed")?.unwrap_or(0);
+    let size = args.parsed_option("--size")?.unwrap_or(DEFAULT_SIZE);
+    let variant = args.option("--variant")?;
+    let params = Params::from_args(&mut args)?;
+    let year = cli::year(&mut args, &registry)?;
+    let day = cli::parse_day(&args.positional().ok_or(crate::USAGE)?)?;
+    args.finish()?;
+    params.apply(&mut registry, year, Some(day))?;
+    let solvers: Vec<&dyn Solver> = match variant {
+        Some(name) => vec![cli::solver(&registry, year, day, Some(&name))?],
+        None => registry.variants(year, day).collect(),
+    };
+    if solvers.is_empty() {
+        cli::solver(&registry, year, day, None)?;
+    }
+    let examples = examples::load(format!("{}/day{:02}/examples", year, day)).unwrap_or_default();
+    for solver in solvers {
+        let seeds = (0..4)
+            .filter_map(|i| solver.generate(seed.wrapping_add(i), size))
+            .map(|generated| generated.input)
+            .chain(examples.iter().map(|example| example.input.clone()))
+            .collect::<Vec<_>>();
+        if seeds.is_empty() {
+            return Err(format!("day {} has no inputs to start from", day));
+        }
+        println!("Day {:02} variant {}", day, solver.variant());
+        fuzz::run(solver, &seeds, &mut Rng::new(seed), iterations)
+            .map_err(|crash| format!("panicked: {}\ninput: {:?}", crash.message, crash.input))?;
+        println!("  no panics in {} inputs", iterations);
+    }
+    Ok(())
+}
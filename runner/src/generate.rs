@@ -0,0 +1,27 @@
+use aoc::Registry;
+
+use crate::cli::{self, Args, Params};
+
+pub const DEFAULT_SIZE: usize = 100;
+
+/// Prints a generated input, and the answers known from how it was built on
+/// stderr so that the input can be redirected to a file on its own.
+pub fn generate(mut registry: Registry, mut args: Args) -> Result<(), String> {
+    let seed = args.parsed_option("--seed")?.unwrap_or(0);
+    let size = args.parsed_option("--size")?.unwrap_or(DEFAULT_SIZE);
+    let variant = args.option("--variant")?;
+    let params = Params::from_args(&mut args)?;
+    let year = cli::year(&mut args, &registry)?;
+    let day = cli::parse_day(&args.positional().ok_or(crate::USAGE)?)?;
+    args.finish()?;
+    params.apply(&mut registry, year, Some(day))?;
+    let solver = cli::solver(&registry, year, day, variant.as_deref())?;
+    let generated = solver
+        .generate(seed, size)
+        .ok_or_else(|| format!("day {} cannot generate an input with these parameters", day))?;
+    print!("{}", generated.input);
+    for (part, answer) in generated.known {
+        eprintln!("Part {}: {}", part.number(), answer);
+    }
+    Ok(())
+}
@@ -0,0 +1,272 @@
+mod animate;
+mod bench;
+mod cli;
+mod fetch;
+mod fuzz;
+mod generate;
+mod new;
+mod registry;
+mod submit;
+mod variants;
+mod verify;
+mod watch;
+
+use std::{env, process, time::Duration};
+
+use aoc::{
+    answers,
+    input::{self, Source},
+    json, Answer, Part, Registry, Solver,
+};
+
+use cli::{Args, Format, Params};
+
+pub const USAGE: &str = "\
+usage: aoc run <day> [--part N] [--variant NAME] [--format FORMAT] [--render PATH [--scale N]]
+               [PARAMS] [INPUT]
+       aoc run --all [--part N] [--format FORMAT] [--params PATH]
+       aoc bench <day> [-n N] [--save PATH] [--baseline PATH] [--variant NAME]
+                 [--generate SIZE [--seed N]] [PARAMS] [INPUT]
+       aoc bench --all [-n N] [--save PATH] [--baseline PATH] [--generate SIZE [--seed N]]
+                 [--params PATH]
+       aoc animate <day> OUTPUT [--every N] [--crop X,Y,W,H] [--scale N] [--delay MS]
+                   [--variant NAME] [PARAMS] [INPUT]
+       aoc verify [<day>] [--accept] [--answers PATH]
+       aoc variants <day> [-n N] [PARAMS] [INPUT]
+       aoc params <day>
+       aoc generate <day> [--seed N] [--size N] [--variant NAME] [PARAMS]
+       aoc fuzz <day> [-n N] [--seed N] [--size N] [--variant NAME] [PARAMS]
+       aoc fetch <day> [--base-url URL]
+       aoc submit <day> <part> [--variant NAME] [--history PATH] [--base-url URL]
+                  [--wait] [PARAMS] [INPUT]
+       aoc new <day>
+       aoc watch <day> [--variant NAME] [PARAMS] [INPUT]
+
+Every command also takes --year YYYY, and works on the days of that year,
+of AOC_YEAR or otherwise of the latest year with solutions. --all means
+every day of that year.
+INPUT is a path, or - for stdin, and defaults to inputs/YYYY/dayNN.txt.
+FORMAT is text, the default, or json for one JSON object per line and part
+with the year, day, variant, part, input_hash, answer (an object with a
+type and a value), parse_ms, part_ms and error, where fields that do not
+apply are null.
+--render draws the solution of days that support it, in colour when PATH
+is - and as an image when it ends in .ppm or .png, with N pixels per cell,
+4 by default.
+animate records the simulation of days that step through one, as an animated
+GIF when OUTPUT ends in .gif, an asciinema cast when it ends in .cast, and
+otherwise as numbered PPM frames in the directory OUTPUT. --every keeps only
+every Nth frame, --crop keeps W by H cells from X,Y and --delay sets the
+time per frame, 100 ms by default.
+verify re-solves inputs/YYYY/dayNN.txt and compares with the accepted
+answers in answers.toml. --accept records the current answers and the time
+each part takes, parsing aside.
+bench times parsing and each part over N iterations, 10 by default. --save
+writes the medians as a baseline that a later --baseline compares against.
+--generate benchmarks a generated input of the given size instead.
+variants runs every variant of a day, checks that they agree and compares
+their timings. Without --variant, the first registered variant runs.
+PARAMS is --params PATH, a TOML file with a [dayNN] or [YYYY.dayNN] table
+of parameters, and any number of --param name=value. params lists a day's
+parameters.
+generate prints a random input for a day from seed N, 0 by default, where
+what the size counts depends on the day, 100 by default. Answers known
+from how the input was built go to stderr.
+fuzz solves N random mutations, 10000 by default, of inputs generated from
+seed N and the next three, 0 by default, of the given size, 10 by default,
+and of the day's examples, and stops at the first input that makes a
+variant panic. The seed also picks the mutations.
+fetch downloads a day's input to inputs/YYYY/dayNN.txt, for any year, with
+the session cookie in AOC_SESSION, unless it is there already, and waits a
+few seconds between requests. The site is https://adventofcode.com unless
+--base-url or AOC_BASE_URL says otherwise; https needs curl on the PATH.
+submit solves a part and submits the answer, unless an earlier attempt in
+the history, submissions.toml by default, shows it is wrong, or the site
+still asks to wait, which --wait sits out. Each verdict goes in the history.
+new creates the crate of a day, for any year, in YYYY/dayNN from the
+template with an empty example, adds it to the workspace members and
+registers it with the runner. Run from the workspace root.
+watch checks a day's examples and then solves INPUT, showing how the answers
+differ from the previous run, and does so again whenever the examples, INPUT
+or the day's source change. A change to the source rebuilds the runner with
+cargo and restarts it. Needs inotify, so Linux, and the workspace root.";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let command = args.next();
+    let args = Args::new(args);
+    let result = match command.as_deref() {
+        Some("run") => run(registry::registry(), args),
+        Some("bench") => bench::bench(registry::registry(), args),
+        Some("animate") => animate::animate(registry::registry(), args),
+        Some("variants") => variants::variants(registry::registry(), args),
+        Some("params") => params(registry::registry(), args),
+        Some("generate") => generate::generate(registry::registry(), args),
+        Some("fuzz") => fuzz::fuzz(registry::registry(), args),
+        Some("fetch") => fetch::fetch(registry::registry(), args),
+        Some("submit") => submit::submit(registry::registry(), args),
+        Some("new") => new::new(registry::registry(), args),
+        Some("watch") => watch::watch(registry::registry(), args),
+        Some("verify") => verify::verify(&registry::registry(), args),
+        Some("help" | "--help" | "-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+fn run(mut registry: Registry, mut args: Args) -> Result<(), String> {
+    let all = args.flag("--all");
+    let parts = cli::parse_parts(args.parsed_option("--part")?)?;
+    let variant = args.option("--variant")?;
+    let format = args.parsed_option("--format")?.unwrap_or(Format::Text);
+    let render = args.option("--render")?;
+    let scale = args.parsed_option("--scale")?.unwrap_or(4);
+    let params = Params::from_args(&mut args)?;
+    let year = cli::year(&mut args, &registry)?;
+    if all {
+        args.finish()?;
+        if variant.is_some() {
+            return Err("--variant needs a day".to_string());
+        }
+        if render.is_some() {
+            return Err("--render needs a day".to_string());
+        }
+        params.apply(&mut registry, year, None)?;
+        let mut failed = 0;
+        for solver in registry.iter(year) {
+            let source = Source::from_arg(None, year, solver.day());
+            let result = match format {
+                Format::Text => {
+                    println!("Day {:02}", solver.day());
+                    run_day(solver, &source, &parts)
+                }
+                Format::Json => run_day_json(solver, &source, &parts),
+            };
+            if let Err(e) = result {
+                eprintln!("{}", e);
+                failed += 1;
+            }
+        }
+        return match failed {
+            0 => Ok(()),
+            _ => Err(format!("{} days failed", failed)),
+        };
+    }
+
+    let day = cli::parse_day(&args.positional().ok_or(USAGE)?)?;
+    let source = Source::from_arg(args.positional().as_deref(), year, day);
+    args.finish()?;
+    params.apply(&mut registry, year, Some(day))?;
+    let solver = cli::solver(&registry, year, day, variant.as_deref())?;
+    if render.as_deref() == Some("-") && format == Format::Json {
+        return Err("--render - would mix with the JSON output".to_string());
+    }
+    match format {
+        Format::Text => run_day(solver, &source, &parts)?,
+        Format::Json => run_day_json(solver, &source, &parts)?,
+    }
+    match render {
+        Some(path) => render_day(solver, &source, &path, scale),
+        None => Ok(()),
+    }
+}
+
+fn params(mut registry: Registry, mut args: Args) -> Result<(), String> {
+    let year = cli::year(&mut args, &registry)?;
+    let day = cli::parse_day(&args.positional().ok_or(USAGE)?)?;
+    args.finish()?;
+    for solver in registry.variants_mut(year, day) {
+        println!("Day {:02} variant {}", day, solver.variant());
+        for (name, value) in solver.params() {
+            println!("  {} = {}", name, value);
+        }
+    }
+    Ok(())
+}
+
+/// Prints the answer of every part that has one, and fails with the errors
+/// of the others.
+fn run_day(solver: &dyn Solver, source: &Source, parts: &[Part]) -> Result<(), String> {
+    let input = input::read(source).map_err(|e| e.to_string())?;
+    let mut errors = Vec::new();
+    for (part, answer) in solver.solve(&input, parts).map_err(|e| e.to_string())? {
+        match answer {
+            Ok(answer) => println!("Part {}: {}", part.number(), answer),
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors.join("\n")),
+    }
+}
+
+fn render_day(
+    solver: &dyn Solver,
+    source: &Source,
+    path: &str,
+    scale: usize,
+) -> Result<(), String> {
+    let input = input::read(source).map_err(|e| e.to_string())?;
+    let render = solver
+        .render(&input)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("day {} has nothing to render", solver.day()))?;
+    render
+        .save(path, scale.max(1))
+        .map_err(|e| format!("{}: {}", path, e))
+}
+
+fn millis(time: Duration) -> json::Value {
+    ((time.as_secs_f64() * 1e6).round() / 1e3).into()
+}
+
+/// Prints a record for every part even when reading, parsing or solving
+/// fails, so that consumers always see one line per day and part. Each part
+/// is solved on its own so a panic in one leaves the other intact.
+fn run_day_json(solver: &dyn Solver, source: &Source, parts: &[Part]) -> Result<(), String> {
+    let input = input::read(source).map_err(|e| e.to_string());
+    let mut failed = 0;
+    for &part in parts {
+        let (answer, parse, time, error) = match &input {
+            Err(e) => (None, None, None, Some(e.clone())),
+            Ok(input) => match cli::catch(|| solver.solve_timed(input, &[part])) {
+                Ok(Ok(mut solved)) => {
+                    let (_, answer, time) = solved.answers.remove(0);
+                    match answer {
+                        Ok(answer) => (Some(answer), Some(solved.parse), Some(time), None),
+                        Err(e) => (None, Some(solved.parse), Some(time), Some(e.to_string())),
+                    }
+                }
+                Ok(Err(e)) => (None, None, None, Some(e.to_string())),
+                Err(message) => (None, None, None, Some(format!("panicked: {}", message))),
+            },
+        };
+        failed += usize::from(error.is_some());
+        let record = json::Value::object([
+            ("year", u64::from(solver.year()).into()),
+            ("day", u64::from(solver.day()).into()),
+            ("variant", solver.variant().into()),
+            ("part", u64::from(part.number()).into()),
+            (
+                "input_hash",
+                input.as_deref().ok().map(answers::input_hash).into(),
+            ),
+            ("answer", answer.as_ref().map(Answer::to_json).into()),
+            ("parse_ms", parse.map(millis).into()),
+            ("part_ms", time.map(millis).into()),
+            ("error", error.into()),
+        ]);
+        println!("{}", record);
+    }
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("day {}: {} parts failed", solver.day(), failed)),
+    }
+}
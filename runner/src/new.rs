@@ -0,0 +1,23 @@
+use std::path::Path;
+
+use aoc::{scaffold, Registry};
+
+use crate::cli::{self, Args};
+
+pub fn new(registry: Registry, mut args: Args) -> Result<(), String> {
+    let year = cli::any_year(&mut args, &registry)?;
+    let day = cli::parse_day(&args.positional().ok_or(crate::USAGE)?)?;
+    args.finish()?;
+    let written = scaffold::new_day(Path::new(""), year, day).map_err(|e| e.to_string())?;
+    for path in written {
+        println!("Wrote {}", path.display());
+    }
+    println!(
+        "Day {:02} of {} is {}, registered as {}",
+        day,
+        year,
+        scaffold::crate_dir(year, day),
+        scaffold::crate_name(year, day)
+    );
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use aoc::Registry;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    year2024(&mut registry);
+    registry
+}
+
+fn year2024(registry: &mut Registry) {
+    registry.register(aoc2024_day01::Day01);
+    registry.register(aoc2024_day02::Day02);
+    registry.register(aoc2024_day03::Day03);
+    registry.register(aoc2024_day04::Day04);
+    registry.register(aoc2024_day05::Day05);
+    registry.register(aoc2024_day06::Day06);
+    registry.register(aoc2024_day07::Day07);
+    registry.register(aoc2024_day08::Day08);
+    registry.register(aoc2024_day09::Day09);
+    registry.register(aoc2024_day10::Day10);
+    registry.register(aoc2024_day11::Day11::default());
+    registry.register(aoc2024_day11::Day11Post::default());
+    registry.register(aoc2024_day12::Day12);
+    registry.register(aoc2024_day13::Day13::default());
+    registry.register(aoc2024_day14::Day14::default());
+    registry.register(aoc2024_day15::Day15);
+    registry.register(aoc2024_day16::Day16);
+    registry.register(aoc2024_day17::Day17);
+    registry.register(aoc2024_day18::Day18::default());
+    registry.register(aoc2024_day19::Day19);
+    registry.register(aoc2024_day19::Day19Post);
+    registry.register(aoc2024_day20::Day20::default());
+    registry.register(aoc2024_day21::Day21::default());
+    registry.register(aoc2024_day22::Day22::default());
+    registry.register(aoc2024_day23::Day23);
+    registry.register(aoc2024_day24::Day24);
+    registry.register(aoc2024_day25::Day25);
+}
@@ -0,0 +1,97 @@
+use std::{thread, time::Duration};
+
+use aoc::{
+    client::Submitted,
+    input::{self, Source},
+    submissions::{self, History, Refusal, Verdict},
+    Part, Registry,
+};
+
+use crate::{
+    cli::{self, Args, Params},
+    fetch,
+};
+
+/// Solves a part and submits the answer, unless the history of earlier
+/// attempts already rules it out or the site still wants us to wait.
+pub fn submit(mut registry: Registry, mut args: Args) -> Result<(), String> {
+    let wait = args.flag("--wait");
+    let variant = args.option("--variant")?;
+    let path = args
+        .option("--history")?
+        .unwrap_or_else(|| submissions::DEFAULT_PATH.to_string());
+    let (client, base_url) = fetch::client(&mut args)?;
+    let params = Params::from_args(&mut args)?;
+    let year = cli::year(&mut args, &registry)?;
+    let day = cli::parse_day(&args.positional().ok_or(crate::USAGE)?)?;
+    let part = args.positional().ok_or(crate::USAGE)?;
+    let part = part
+        .parse()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| format!("not a part: {}", part))?;
+    let source = Source::from_arg(args.positional().as_deref(), year, day);
+    args.finish()?;
+    params.apply(&mut registry, year, Some(day))?;
+    let solver = cli::solver(&registry, year, day, variant.as_deref())?;
+
+    let input = input::read(&source).map_err(|e| e.to_string())?;
+    let answer = solver
+        .solve_part(&input, part)
+        .map_err(|e| e.to_string())?
+        .to_string();
+    println!("Day {:02} part {}: {}", day, part.number(), answer);
+
+    let mut history = History::load(&path).map_err(|e| e.to_string())?;
+    match history.check(year, day, part, &answer) {
+        Ok(()) => {}
+        Err(Refusal::Accepted) => {
+            println!("  already accepted");
+            return Ok(());
+        }
+        Err(refusal) => return Err(format!("not submitted, {}", refusal)),
+    }
+    let left = history.wait(year, day, part);
+    if left > Duration::ZERO {
+        if !wait {
+            return Err(format!(
+                "not submitted, the site takes no answer for another {}s, or run with --wait",
+                left.as_secs_f64().ceil()
+            ));
+        }
+        println!("  waiting {}s", left.as_secs_f64().ceil());
+        thread::sleep(left);
+    }
+
+    let submitted = client
+        .submit(year, day, part, &answer)
+        .map_err(|e| fetch::client_error(e, &base_url))?;
+    let result = match submitted {
+        Submitted::Verdict(verdict, wait) => {
+            history.record(year, day, part, &answer, verdict);
+            if wait > Duration::ZERO {
+                history.wait_for(year, day, part, wait);
+            }
+            if verdict == Verdict::Correct {
+                println!("  correct");
+                Ok(())
+            } else {
+                Err(format!("{}, next answer in {}s", verdict, wait.as_secs()))
+            }
+        }
+        Submitted::TooSoon(wait) => {
+            history.wait_for(year, day, part, wait);
+            Err(format!("too soon, next answer in {}s", wait.as_secs()))
+        }
+        Submitted::AlreadySolved => Err(format!(
+            "day {} part {} of {} is already solved, or not unlocked yet",
+            day,
+            part.number(),
+            year
+        )),
+    };
+    history
+        .save(&path)
+        .map_err(|e| format!("{}: {}", path, e))?;
+    result
+}
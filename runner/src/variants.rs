@@ -0,0 +1,86 @@
+use aoc::{
+    bench,
+    input::{self, Source},
+    Answer, Part, Registry,
+};
+
+use crate::{
+    bench::DEFAULT_ITERATIONS,
+    cli::{self, catch, Args, Params},
+};
+
+pub fn variants(mut registry: Registry, mut args: Args) -> Result<(), String> {
+    let iterations = args
+        .parsed_option("-n")?
+        .unwrap_or(DEFAULT_ITERATIONS)
+        .max(1);
+    let params = Params::from_args(&mut args)?;
+    let year = cli::year(&mut args, &registry)?;
+    let day = cli::parse_day(&args.positional().ok_or(crate::USAGE)?)?;
+    let source = Source::from_arg(args.positional().as_deref(), year, day);
+    args.finish()?;
+    params.apply(&mut registry, year, Some(day))?;
+    let input = input::read(&source).map_err(|e| e.to_string())?;
+
+    let mut solved: Vec<(&str, Vec<(Part, Answer)>)> = Vec::new();
+    let mut failed = 0;
+    for solver in registry.variants(year, day) {
+        println!("Day {:02} variant {}", day, solver.variant());
+        let timings = match catch(|| solver.bench(&input, iterations)) {
+            Ok(Ok(timings)) => timings,
+            Ok(Err(e)) => {
+                println!("  {}", e);
+                failed += 1;
+                continue;
+            }
+            Err(message) => {
+                println!("  panicked: {}", message);
+                failed += 1;
+                continue;
+            }
+        };
+        for (phase, summary) in timings.summaries() {
+            println!(
+                "  {:<5}  median {:>10.3} ms  min {:>10.3} ms  max {:>10.3} ms",
+                phase,
+                bench::millis(summary.median),
+                bench::millis(summary.min),
+                bench::millis(summary.max)
+            );
+        }
+        // Benchmarking already showed that solving neither fails nor panics.
+        let answers = solver
+            .solve(&input, &Part::BOTH)
+            .unwrap()
+            .into_iter()
+            .map(|(part, answer)| (part, answer.unwrap()))
+            .collect::<Vec<_>>();
+        for (part, answer) in &answers {
+            println!("  Part {}: {}", part.number(), answer);
+        }
+        solved.push((solver.variant(), answers));
+    }
+
+    let Some((first, expected)) = solved.first() else {
+        return Err(format!("day {} of {} is not solved yet", day, year));
+    };
+    for (variant, answers) in &solved[1..] {
+        for ((part, answer), (_, expected)) in answers.iter().zip(expected) {
+            if answer != expected {
+                println!(
+                    "Part {}: {} says {}, but {} says {}",
+                    part.number(),
+                    variant,
+                    answer,
+                    first,
+                    expected
+                );
+                failed += 1;
+            }
+        }
+    }
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} variants failed or disagree", failed)),
+    }
+}
@@ -0,0 +1,135 @@
+use std::time::{Duration, Instant};
+
+use aoc::{
+    answers::{self, Answers},
+    input::{self, Source},
+    Answer, Part, Registry,
+};
+
+use crate::cli::{self, catch, Args};
+
+/// A part counts as slower once it takes this much longer than accepted,
+/// plus a millisecond of slack for the fast days.
+const SLOWER_FACTOR: f64 = 1.5;
+const SLOWER_SLACK_MS: f64 = 1.0;
+
+enum Outcome {
+    Ok,
+    New,
+    Drifted(String),
+    Crashed(String),
+    Slower(f64),
+}
+
+fn millis(time: Duration) -> f64 {
+    time.as_secs_f64() * 1e3
+}
+
+pub fn verify(registry: &Registry, mut args: Args) -> Result<(), String> {
+    let accept = args.flag("--accept");
+    let path = args
+        .option("--answers")?
+        .unwrap_or_else(|| answers::DEFAULT_PATH.to_string());
+    let year = cli::year(&mut args, registry)?;
+    let day = args.positional().map(|s| cli::parse_day(&s)).transpose()?;
+    args.finish()?;
+
+    let mut answers = Answers::load(&path).map_err(|e| e.to_string())?;
+    let (mut ok, mut new, mut drifted, mut crashed, mut slower) = (0, 0, 0, 0, 0);
+    for solver in registry
+        .iter(year)
+        .filter(|s| day.is_none_or(|d| d == s.day()))
+    {
+        let source = Source::from_arg(None, year, solver.day());
+        let input = match input::read(&source) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Day {:02}: skipped, {}", solver.day(), e);
+                continue;
+            }
+        };
+        let hash = answers::input_hash(&input);
+        for part in Part::BOTH {
+            // Only the part itself is timed, as bench does, since parsing is
+            // the same for both.
+            let start = Instant::now();
+            let result = catch(|| solver.solve_timed(&input, &[part]));
+            let (answer, elapsed): (Result<Answer, String>, Duration) = match result {
+                Ok(Ok(mut solved)) => {
+                    let (_, answer, elapsed) = solved.answers.remove(0);
+                    (answer.map_err(|e| e.to_string()), elapsed)
+                }
+                Ok(Err(e)) => (Err(e.to_string()), start.elapsed()),
+                Err(message) => (Err(format!("panicked: {}", message)), start.elapsed()),
+            };
+            let accepted = answers.get(year, solver.day(), &hash, part);
+            let outcome = match (&answer, &accepted) {
+                (Err(e), _) => Outcome::Crashed(e.clone()),
+                (Ok(_), None) => Outcome::New,
+                (Ok(answer), Some(accepted)) if answer.to_string() != accepted.answer => {
+                    Outcome::Drifted(accepted.answer.clone())
+                }
+                (Ok(_), Some(accepted)) => match accepted.millis {
+                    Some(was) if millis(elapsed) > was * SLOWER_FACTOR + SLOWER_SLACK_MS => {
+                        Outcome::Slower(was)
+                    }
+                    _ => Outcome::Ok,
+                },
+            };
+            let shown = answer
+                .as_ref()
+                .map_or_else(|_| "-".to_string(), ToString::to_string);
+            let status = match &outcome {
+                Outcome::Ok => {
+                    ok += 1;
+                    "ok".to_string()
+                }
+                Outcome::New => {
+                    new += 1;
+                    "new".to_string()
+                }
+                Outcome::Drifted(expected) => {
+                    drifted += 1;
+                    format!("DRIFTED, accepted {}", expected)
+                }
+                Outcome::Crashed(e) => {
+                    crashed += 1;
+                    format!("CRASHED, {}", e)
+                }
+                Outcome::Slower(was) => {
+                    slower += 1;
+                    format!("slower, accepted in {:.3} ms", was)
+                }
+            };
+            println!(
+                "Day {:02} part {}: {} ({:.3} ms) {}",
+                solver.day(),
+                part.number(),
+                shown,
+                millis(elapsed),
+                status
+            );
+            if let (true, Ok(answer)) = (accept, &answer) {
+                answers.accept(year, solver.day(), &hash, part, answer, elapsed);
+            }
+        }
+    }
+
+    println!(
+        "{} ok, {} new, {} drifted, {} crashed, {} slower",
+        ok, new, drifted, crashed, slower
+    );
+    if accept {
+        answers
+            .save(&path)
+            .map_err(|e| format!("{}: {}", path, e))?;
+        println!("Accepted answers written to {}", path);
+    } else if new > 0 {
+        println!("Run with --accept to record the new answers");
+    }
+    // Accepting makes the drifted answers the new accepted ones.
+    match crashed + if accept { 0 } else { drifted } {
+        0 => Ok(()),
+        failed => Err(format!("{} answers drifted or crashed", failed)),
+    }
+}
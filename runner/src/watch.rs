@@ -0,0 +1,243 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+    time::Instant,
+};
+
+use aoc::{
+    examples,
+    input::{self, Source},
+    scaffold,
+    toml::{Document, Value},
+    watch::Watcher,
+    Part, Registry, Solver,
+};
+
+use crate::cli::{self, catch, Args, Params};
+
+/// The answers of the last run, as TOML, for the process that replaces this
+/// one after a rebuild.
+const ANSWERS_VAR: &str = "AOC_WATCH_ANSWERS";
+
+type Answers = Vec<(Part, String)>;
+
+fn load_answers() -> Answers {
+    let Some(document) = env::var(ANSWERS_VAR)
+        .ok()
+        .and_then(|text| Document::parse(&text).ok())
+    else {
+        return Vec::new();
+    };
+    Part::BOTH
+        .into_iter()
+        .filter_map(|part| {
+            let answer = document
+                .root()?
+                .get(&format!("part{}", part.number()))
+                .and_then(Value::as_str)?;
+            Some((part, answer.to_string()))
+        })
+        .collect()
+}
+
+fn save_answers(answers: &Answers) -> String {
+    let mut document = Document::default();
+    for (part, answer) in answers {
+        document
+            .table_mut("")
+            .insert(format!("part{}", part.number()), answer.as_str());
+    }
+    document.to_string()
+}
+
+fn run_examples(solver: &mut dyn Solver, dir: &Path) {
+    let examples = match examples::load(dir) {
+        Ok(examples) => examples,
+        Err(e) => {
+            println!("Examples: {}", e);
+            return;
+        }
+    };
+    println!("Examples");
+    for example in &examples {
+        let saved = solver.params();
+        let mismatches = match catch(|| examples::check(solver, example)) {
+            Ok(mismatches) => mismatches,
+            Err(message) => {
+                // A panic skips restoring the parameters of the example.
+                solver.restore_params(&saved);
+                println!("  {}: panicked: {}", example.name, message);
+                continue;
+            }
+        };
+        match (example.expected.is_empty(), mismatches.is_empty()) {
+            (true, _) => println!("  {}: no answers to check", example.name),
+            (false, true) => println!("  {}: ok", example.name),
+            (false, false) => {
+                for mismatch in mismatches {
+                    println!("  {}", mismatch);
+                }
+            }
+        }
+    }
+}
+
+/// Solves each part on its own and prints how the answer compares with
+/// `previous`, returning the latest answers, where a part that failed keeps
+/// its previous one.
+fn run_input(solver: &dyn Solver, source: &Source, previous: &Answers) -> Answers {
+    let input = match input::read(source) {
+        Ok(input) => input,
+        Err(e) => {
+            println!("Input: {}", e);
+            return previous.clone();
+        }
+    };
+    println!("Input");
+    let mut answers = Vec::new();
+    for part in Part::BOTH {
+        let was = previous
+            .iter()
+            .find(|(p, _)| *p == part)
+            .map(|(_, answer)| answer.clone());
+        let start = Instant::now();
+        let result = catch(|| solver.solve_part(&input, part));
+        let millis = start.elapsed().as_secs_f64() * 1e3;
+        let answer = match result {
+            Ok(Ok(answer)) => answer.to_string(),
+            Ok(Err(e)) => {
+                println!("  Part {}: {}", part.number(), e);
+                answers.extend(was.map(|was| (part, was)));
+                continue;
+            }
+            Err(message) => {
+                println!("  Part {}: panicked: {}", part.number(), message);
+                answers.extend(was.map(|was| (part, was)));
+                continue;
+            }
+        };
+        let change = match was {
+            None => String::new(),
+            Some(was) if was == answer => ", unchanged".to_string(),
+            Some(was) => format!(", was {}", was),
+        };
+        println!(
+            "  Part {}: {} ({:.3} ms){}",
+            part.number(),
+            answer,
+            millis,
+            change
+        );
+        answers.push((part, answer));
+    }
+    answers
+}
+
+/// Builds the runner with the profile of this one, leaving cargo's output
+/// on the terminal.
+fn rebuild() -> Result<bool, String> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command.args(["build", "-p", "runner"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    let status = command.status().map_err(|e| format!("cargo: {}", e))?;
+    Ok(status.success())
+}
+
+/// Replaces this process with the rebuilt runner, handing it the answers.
+#[cfg(unix)]
+fn restart(exe: &Path, answers: &Answers) -> Result<(), String> {
+    use std::os::unix::process::CommandExt;
+
+    let e = Command::new(exe)
+        .args(env::args_os().skip(1))
+        .env(ANSWERS_VAR, save_answers(answers))
+        .exec();
+    Err(format!("{}: {}", exe.display(), e))
+}
+
+#[cfg(not(unix))]
+fn restart(exe: &Path, answers: &Answers) -> Result<(), String> {
+    let status = Command::new(exe)
+        .args(env::args_os().skip(1))
+        .env(ANSWERS_VAR, save_answers(answers))
+        .status()
+        .map_err(|e| format!("{}: {}", exe.display(), e))?;
+    std::process::exit(status.code().unwrap_or(1))
+}
+
+/// Checks the examples and then solves the input every time either of them
+/// or the day's source changes, rebuilding and restarting on the latter.
+pub fn watch(mut registry: Registry, mut args: Args) -> Result<(), String> {
+    let variant = args.option("--variant")?;
+    let params = Params::from_args(&mut args)?;
+    let year = cli::year(&mut args, &registry)?;
+    let day = cli::parse_day(&args.positional().ok_or(crate::USAGE)?)?;
+    let source = Source::from_arg(args.positional().as_deref(), year, day);
+    args.finish()?;
+    let Source::Path(input) = &source else {
+        return Err("watch needs an input file rather than stdin".to_string());
+    };
+    params.apply(&mut registry, year, Some(day))?;
+    let name = cli::solver(&registry, year, day, variant.as_deref())?
+        .variant()
+        .to_string();
+    let solver = registry
+        .variants_mut(year, day)
+        .find(|solver| solver.variant() == name)
+        .unwrap();
+
+    // Taken now, as the rebuild replaces the file.
+    let exe = env::current_exe().map_err(|e| e.to_string())?;
+    let dir = PathBuf::from(scaffold::crate_dir(year, day));
+    if !dir.is_dir() {
+        return Err(format!(
+            "{} does not exist, watch runs from the workspace root",
+            dir.display()
+        ));
+    }
+    let (src, examples) = (dir.join("src"), dir.join("examples"));
+    let mut watched = vec![src.clone(), examples.clone()];
+    match input
+        .parent()
+        .is_none_or(|parent| parent == Path::new("") || parent.is_dir())
+    {
+        true => watched.push(input.clone()),
+        false => println!(
+            "Not watching {}, its directory does not exist",
+            input.display()
+        ),
+    }
+    let mut watcher = Watcher::new(&watched).map_err(|e| e.to_string())?;
+    let watched = watched
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut answers = load_answers();
+    loop {
+        println!("Day {:02} variant {}", day, solver.variant());
+        run_examples(solver, &examples);
+        answers = run_input(solver, &source, &answers);
+        loop {
+            println!("Watching {}", watched);
+            let changed = watcher.wait().map_err(|e| e.to_string())?;
+            let names = changed
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>();
+            println!("Changed {}", names.join(", "));
+            if !changed.iter().any(|path| path.starts_with(&src)) {
+                break;
+            }
+            if rebuild()? {
+                return restart(&exe, &answers);
+            }
+            println!("The build failed, waiting for a fix");
+        }
+    }
+}